@@ -0,0 +1,128 @@
+use aoc_core::{Answer, Result, Solution, parse};
+use std::collections::HashSet;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse::lines(input)
+    }
+
+    fn part1(&self, changes: &Self::Parsed) -> Result<Answer> {
+        Ok(changes.iter().map(|&c| i64::from(c)).sum::<i64>().into())
+    }
+
+    fn part2(&self, changes: &Self::Parsed) -> Result<Answer> {
+        Ok(first_repeat(changes)?.into())
+    }
+}
+
+/// The first frequency reached twice while applying `changes` over and over,
+/// starting from zero.
+///
+/// After pass `k` the frequency reached at step `i` is `sums[i] + k * drift`,
+/// so a value can only come back if some other prefix sum lies a whole number
+/// of drifts ahead of it. Grouping the prefix sums by their remainder modulo
+/// the drift and sorting each group finds every such pair at once.
+pub fn first_repeat(changes: &[i32]) -> Result<i64> {
+    if changes.is_empty() {
+        return Err("no frequency changes".into());
+    }
+    let mut sums = Vec::with_capacity(changes.len());
+    let mut frequency = 0i64;
+    for &change in changes {
+        sums.push(frequency);
+        frequency += i64::from(change);
+    }
+    let drift = frequency;
+
+    // A repeat within the first pass comes before any in later passes.
+    let mut seen = HashSet::with_capacity(sums.len() + 1);
+    for &sum in sums.iter().chain([&drift]) {
+        if !seen.insert(sum) {
+            return Ok(sum);
+        }
+    }
+
+    // `sums[i]` comes back as `sums[j]` on pass `k`, at step `k * n + i`, when
+    // `sums[j] - sums[i] == k * drift`. Within a group sorted along the drift,
+    // the nearest next sum gives each `i` its smallest `k`.
+    let mut order: Vec<usize> = (0..sums.len()).collect();
+    order.sort_unstable_by_key(|&i| (sums[i].rem_euclid(drift), sums[i] * drift.signum()));
+    order
+        .windows(2)
+        .filter(|pair| sums[pair[0]].rem_euclid(drift) == sums[pair[1]].rem_euclid(drift))
+        .map(|pair| {
+            let (i, j) = (pair[0], pair[1]);
+            ((sums[j] - sums[i]) / drift, i, sums[j])
+        })
+        .min()
+        .map(|(_, _, frequency)| frequency)
+        .ok_or_else(|| "no frequency is ever reached twice".into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_core::testing::Rng;
+
+    /// The frequency loop from the puzzle text, giving up after `passes`.
+    fn brute_force(changes: &[i32], passes: usize) -> Option<i64> {
+        let mut seen = HashSet::from([0]);
+        let mut frequency = 0i64;
+        for &change in changes.iter().cycle().take(changes.len() * passes) {
+            frequency += i64::from(change);
+            if !seen.insert(frequency) {
+                return Some(frequency);
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn part2_examples() -> Result<()> {
+        for (input, expected) in [
+            ("+1\n-1", 0),
+            ("+3\n+3\n+4\n-2\n-4", 10),
+            ("-6\n+3\n+8\n+5\n-6", 5),
+            ("+7\n+7\n-2\n-7\n-4", 14),
+        ] {
+            let changes = Day1.parse(input)?;
+            assert_eq!(Day1.part2(&changes)?, Answer::Int(expected));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn missing_repeats_are_errors() {
+        assert!(first_repeat(&[]).is_err());
+        assert!(first_repeat(&[1, 1]).is_err());
+        assert!(first_repeat(&[-5]).is_err());
+        assert_eq!(first_repeat(&[0]).ok(), Some(0));
+    }
+
+    #[test]
+    fn large_changes_do_not_overflow() {
+        assert_eq!(
+            first_repeat(&[i32::MAX, i32::MAX, -i32::MAX, -i32::MAX]).ok(),
+            Some(i64::from(i32::MAX))
+        );
+    }
+
+    #[test]
+    fn first_repeat_matches_brute_force() {
+        let mut rng = Rng::new(1);
+        for _ in 0..2000 {
+            let len = 1 + rng.below(12) as usize;
+            let changes: Vec<i32> = (0..len).map(|_| rng.below(21) as i32 - 10).collect();
+            // Sums stay within ±120, so any repeat comes within 240 passes.
+            assert_eq!(
+                first_repeat(&changes).ok(),
+                brute_force(&changes, 300),
+                "{changes:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use aoc_core::similarity::{self, BkTree, Metric};
+use aoc_core::{Answer, Result, Solution};
+use std::collections::HashMap;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(&self, ids: &Self::Parsed) -> Result<Answer> {
+        let mut doubles: u32 = 0;
+        let mut triples: u32 = 0;
+        let mut counts = HashMap::<char, u32>::new();
+        for line in ids {
+            counts.clear();
+            for char in line.chars() {
+                counts
+                    .entry(char)
+                    .and_modify(|counter| *counter += 1)
+                    .or_insert(1);
+            }
+            if counts.values().any(|v| *v == 2) {
+                doubles += 1;
+            };
+            if counts.values().any(|v| *v == 3) {
+                triples += 1;
+            }
+        }
+        Ok((doubles * triples).into())
+    }
+
+    /// The common letters of the one near-duplicate pair, or one line per pair
+    /// when the input has several.
+    fn part2(&self, ids: &Self::Parsed) -> Result<Answer> {
+        let mut pairs = near_duplicates(ids);
+        match pairs.len() {
+            0 => Err("no pair of ids differs by exactly one character".into()),
+            1 => Ok(pairs.remove(0).common.into()),
+            _ => Ok(Answer::Grid(
+                pairs.iter().map(ToString::to_string).collect(),
+            )),
+        }
+    }
+}
+
+/// Two ids that differ in exactly one position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NearDuplicate {
+    pub first: String,
+    pub second: String,
+    /// The letters the two ids share, in order.
+    pub common: String,
+}
+
+impl std::fmt::Display for NearDuplicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} ({} {})", self.common, self.first, self.second)
+    }
+}
+
+/// Every pair of ids that differ in exactly one position, ordered by where the
+/// pair's first id appears in `ids`.
+///
+/// Each id is filed once per position under the id with that position cut
+/// out, so only ids sharing such a key are compared: O(N·K²) for N ids of
+/// length K instead of comparing all pairs.
+pub fn near_duplicates<S: AsRef<str>>(ids: &[S]) -> Vec<NearDuplicate> {
+    let mut buckets = HashMap::<(usize, &str, &str), Vec<usize>>::new();
+    for (i, id) in ids.iter().enumerate() {
+        let id = id.as_ref();
+        for (position, (start, c)) in id.char_indices().enumerate() {
+            let key = (position, &id[..start], &id[start + c.len_utf8()..]);
+            buckets.entry(key).or_default().push(i);
+        }
+    }
+    let mut pairs = vec![];
+    for bucket in buckets.values() {
+        for (n, &i) in bucket.iter().enumerate() {
+            for &j in &bucket[n + 1..] {
+                let (first, second) = (ids[i].as_ref(), ids[j].as_ref());
+                if let Some(common) = find_common_id(first, second) {
+                    pairs.push((i, j, common));
+                }
+            }
+        }
+    }
+    pairs.sort();
+    pairs
+        .into_iter()
+        .map(|(i, j, common)| NearDuplicate {
+            first: ids[i].as_ref().to_string(),
+            second: ids[j].as_ref().to_string(),
+            common,
+        })
+        .collect()
+}
+
+fn tree<S: AsRef<str>>(ids: &[S], metric: Metric) -> BkTree {
+    let mut tree = BkTree::new(metric);
+    for id in ids {
+        tree.insert(id.as_ref());
+    }
+    tree
+}
+
+/// The distinct ids that differ from `query` in at most `k` positions, with
+/// how many, in the order they first appear in `ids`.
+pub fn near_ids<S: AsRef<str>>(ids: &[S], query: &str, k: usize) -> Vec<(String, usize)> {
+    tree(ids, Metric::Hamming)
+        .within(query, k)
+        .into_iter()
+        .map(|(id, distance)| (id.to_string(), distance))
+        .collect()
+}
+
+/// Groups the distinct ids into families linked by chains of ids at most `k`
+/// apart under `metric`, in the order they first appear in `ids`.
+pub fn families<S: AsRef<str>>(ids: &[S], k: usize, metric: Metric) -> Vec<Vec<String>> {
+    tree(ids, metric)
+        .clusters(k)
+        .into_iter()
+        .map(|family| family.into_iter().map(str::to_string).collect())
+        .collect()
+}
+
+/// The common letters of two ids of the same length that differ in exactly one
+/// position.
+fn find_common_id(s1: &str, s2: &str) -> Option<String> {
+    off_by_one(s1, s2).then(|| similarity::common_letters(s1, s2))
+}
+
+/// Whether `s2` is one of the ids exactly one position away from `s1`.
+fn off_by_one(s1: &str, s2: &str) -> bool {
+    s1.chars().count() == s2.chars().count()
+        && near_ids(&[s2], s1, 1)
+            .iter()
+            .any(|&(_, distance)| distance == 1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_off_by_one() {
+        let s1 = "abcd";
+        let s2 = "abce";
+        assert!(off_by_one(s1, s2));
+    }
+    #[test]
+    fn test_not_off_by_one() {
+        let s1 = "abcd";
+        let s2 = "abfe";
+        assert!(!off_by_one(s1, s2));
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let ids = Day2.parse(include_str!("../input/example2.txt"))?;
+        assert_eq!(Day2.part2(&ids)?, Answer::Text("fgij".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn near_ids_and_families_of_example() -> Result<()> {
+        let ids = Day2.parse(include_str!("../input/example2.txt"))?;
+        assert_eq!(
+            near_ids(&ids, "fghij", 1),
+            [("fghij".to_string(), 0), ("fguij".to_string(), 1)]
+        );
+        assert_eq!(near_ids(&ids, "abcde", 2).len(), 2);
+        let close = families(&ids, 1, Metric::Hamming);
+        assert_eq!(close.len(), 6);
+        assert_eq!(close[1], ["fghij", "fguij"]);
+        assert_eq!(
+            families(&ids, 2, Metric::Levenshtein)[0],
+            ["abcde", "axcye"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn all_near_duplicates() {
+        let ids = ["abcd", "abce", "xbcd", "abcd", "zzzz", "abc"];
+        let pairs: Vec<String> = near_duplicates(&ids)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            pairs,
+            [
+                "abc (abcd abce)",
+                "bcd (abcd xbcd)",
+                "abc (abce abcd)",
+                "bcd (xbcd abcd)",
+            ]
+        );
+    }
+}
@@ -0,0 +1,193 @@
+//! Area and overlap questions about a set of rectangles, answered with a sweep
+//! line over compressed coordinates.
+//!
+//! Memory is linear in the number of rectangles and the work is quadratic in
+//! it at worst; neither depends on how large the rectangles are.
+
+use aoc_core::grid::BBox;
+
+/// One edge of a rectangle, crossed while sweeping in `x`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Event {
+    x: u64,
+    /// Removals sort before insertions at the same `x`, so rectangles that
+    /// only touch are not counted as overlapping.
+    kind: EventKind,
+    index: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum EventKind {
+    Remove,
+    Insert,
+}
+
+pub struct Coverage {
+    /// Distinct `y` edges, so segment `s` spans `ys[s]..ys[s + 1]`.
+    ys: Vec<u64>,
+    /// The half-open range of `y` segments each rectangle covers.
+    spans: Vec<(usize, usize)>,
+    events: Vec<Event>,
+}
+
+impl Coverage {
+    pub fn new<I: IntoIterator<Item = BBox<u32>>>(boxes: I) -> Self {
+        let boxes: Vec<BBox<u32>> = boxes.into_iter().collect();
+        let mut ys: Vec<u64> = boxes
+            .iter()
+            .flat_map(|b| [b.min.y as u64, b.max.y as u64 + 1])
+            .collect();
+        ys.sort_unstable();
+        ys.dedup();
+        let segment = |y: u64| ys.binary_search(&y).expect("every edge is in ys");
+        let spans = boxes
+            .iter()
+            .map(|b| (segment(b.min.y as u64), segment(b.max.y as u64 + 1)))
+            .collect();
+        let mut events: Vec<Event> = boxes
+            .iter()
+            .enumerate()
+            .flat_map(|(index, b)| {
+                [
+                    Event {
+                        x: b.min.x as u64,
+                        kind: EventKind::Insert,
+                        index,
+                    },
+                    Event {
+                        x: b.max.x as u64 + 1,
+                        kind: EventKind::Remove,
+                        index,
+                    },
+                ]
+            })
+            .collect();
+        events.sort_unstable();
+        Coverage { ys, spans, events }
+    }
+
+    /// The area covered by at least `k` rectangles. `k` must be positive.
+    pub fn area_at_least(&self, k: u32) -> u64 {
+        assert!(k > 0, "every cell is covered at least zero times");
+        let k = k as i64;
+        // `delta[s]` is the change in depth from segment `s - 1` to segment `s`.
+        let mut delta = vec![0i64; self.ys.len()];
+        let mut area = 0;
+        let mut covered = 0;
+        let mut previous_x = 0;
+        for (i, event) in self.events.iter().enumerate() {
+            area += covered * (event.x - previous_x);
+            previous_x = event.x;
+            let (start, end) = self.spans[event.index];
+            let change = match event.kind {
+                EventKind::Insert => 1,
+                EventKind::Remove => -1,
+            };
+            delta[start] += change;
+            delta[end] -= change;
+            if self.events.get(i + 1).is_some_and(|next| next.x == event.x) {
+                continue;
+            }
+            let mut depth = 0;
+            covered = 0;
+            for (s, window) in self.ys.windows(2).enumerate() {
+                depth += delta[s];
+                if depth >= k {
+                    covered += window[1] - window[0];
+                }
+            }
+        }
+        area
+    }
+
+    /// Indices of the rectangles that overlap no other rectangle, in input order.
+    ///
+    /// Two rectangles overlap exactly when one starts while the other is
+    /// active and their `y` spans intersect, so each insertion is checked
+    /// against the rectangles active at that point of the sweep.
+    pub fn isolated(&self) -> Vec<usize> {
+        let mut overlapped = vec![false; self.spans.len()];
+        let mut active: Vec<usize> = vec![];
+        for event in &self.events {
+            match event.kind {
+                EventKind::Remove => active.retain(|&i| i != event.index),
+                EventKind::Insert => {
+                    let (start, end) = self.spans[event.index];
+                    for &other in &active {
+                        let (other_start, other_end) = self.spans[other];
+                        if start < other_end && other_start < end {
+                            overlapped[event.index] = true;
+                            overlapped[other] = true;
+                        }
+                    }
+                    active.push(event.index);
+                }
+            }
+        }
+        (0..overlapped.len()).filter(|&i| !overlapped[i]).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::boxes;
+    use aoc_core::grid::{Point, SparseGrid};
+
+    fn depths(boxes: &[BBox<u32>]) -> SparseGrid<u32, u32> {
+        let mut depths = SparseGrid::new();
+        for b in boxes {
+            for p in b.points() {
+                *depths.entry(p).or_insert(0) += 1;
+            }
+        }
+        depths
+    }
+
+    #[test]
+    fn area_matches_cell_count() {
+        for seed in 0..20 {
+            let boxes = boxes(8, seed);
+            let depths = depths(&boxes);
+            let coverage = Coverage::new(boxes.iter().copied());
+            for k in 1..=4 {
+                let expected = depths.values().filter(|d| **d >= k).count() as u64;
+                assert_eq!(coverage.area_at_least(k), expected, "seed {seed}, k {k}");
+            }
+        }
+    }
+
+    #[test]
+    fn isolated_matches_cell_count() {
+        for seed in 0..20 {
+            let boxes = boxes(6, seed);
+            let depths = depths(&boxes);
+            let expected: Vec<usize> = (0..boxes.len())
+                .filter(|&i| boxes[i].points().all(|p| depths[p] == 1))
+                .collect();
+            assert_eq!(Coverage::new(boxes).isolated(), expected, "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn touching_boxes_do_not_overlap() {
+        let a = BBox::from_size(Point::new(0, 0), 2, 2);
+        let b = BBox::from_size(Point::new(2, 0), 2, 2);
+        let coverage = Coverage::new([a, b]);
+        assert_eq!(coverage.area_at_least(2), 0);
+        assert_eq!(coverage.area_at_least(1), 8);
+        assert_eq!(coverage.isolated(), vec![0, 1]);
+    }
+
+    #[test]
+    fn huge_boxes() {
+        let a = BBox::from_size(Point::new(0, 0), 3_000_000, 2_000_000);
+        let b = BBox::from_size(Point::new(1_000_000, 1_000_000), 4_000_000, 4_000_000);
+        let coverage = Coverage::new([a, b]);
+        assert_eq!(coverage.area_at_least(2), 2_000_000 * 1_000_000);
+        assert_eq!(
+            coverage.area_at_least(1),
+            6_000_000_000_000 + 16_000_000_000_000 - 2_000_000_000_000
+        );
+    }
+}
@@ -0,0 +1,17 @@
+//! Rectangles shared by the randomized tests of [`crate::coverage`] and
+//! [`crate::index`].
+
+use aoc_core::grid::{BBox, Point};
+use aoc_core::testing::Rng;
+
+/// Small deterministic boxes, including ones that touch or nest.
+pub fn boxes(count: usize, seed: u64) -> Vec<BBox<u32>> {
+    let mut rng = Rng::new(seed);
+    let mut next = |bound| rng.below(bound) as u32;
+    (0..count)
+        .map(|_| {
+            let origin = Point::new(next(12), next(12));
+            BBox::from_size(origin, next(6) + 1, next(6) + 1)
+        })
+        .collect()
+}
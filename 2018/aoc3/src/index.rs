@@ -0,0 +1,223 @@
+//! An R-tree over rectangles for intersection queries and the overlap graph.
+//!
+//! The tree is bulk-loaded with Sort-Tile-Recursive packing: rectangles are
+//! sorted into vertical slices by their centre, each slice is sorted by height
+//! and cut into nodes, and the same packing is repeated on the nodes until
+//! one root is left.
+
+use aoc_core::grid::BBox;
+
+const NODE_SIZE: usize = 8;
+
+enum Node {
+    Leaf {
+        bbox: BBox<u32>,
+        entries: Vec<usize>,
+    },
+    Branch {
+        bbox: BBox<u32>,
+        children: Vec<Node>,
+    },
+}
+
+impl Node {
+    fn bbox(&self) -> BBox<u32> {
+        match self {
+            Node::Leaf { bbox, .. } | Node::Branch { bbox, .. } => *bbox,
+        }
+    }
+}
+
+pub struct OverlapIndex {
+    boxes: Vec<BBox<u32>>,
+    root: Option<Node>,
+}
+
+impl OverlapIndex {
+    pub fn new<I: IntoIterator<Item = BBox<u32>>>(boxes: I) -> Self {
+        let boxes: Vec<BBox<u32>> = boxes.into_iter().collect();
+        let mut nodes: Vec<Node> = pack((0..boxes.len()).collect(), |&i| boxes[i])
+            .into_iter()
+            .map(|entries| Node::Leaf {
+                bbox: enclosing(entries.iter().map(|&i| boxes[i])),
+                entries,
+            })
+            .collect();
+        while nodes.len() > 1 {
+            nodes = pack(nodes, Node::bbox)
+                .into_iter()
+                .map(|children| Node::Branch {
+                    bbox: enclosing(children.iter().map(Node::bbox)),
+                    children,
+                })
+                .collect();
+        }
+        OverlapIndex {
+            boxes,
+            root: nodes.pop(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.boxes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.boxes.is_empty()
+    }
+
+    /// Indices of the rectangles that share at least one cell with `area`, in
+    /// increasing order.
+    pub fn query(&self, area: &BBox<u32>) -> Vec<usize> {
+        let mut found = vec![];
+        let mut stack: Vec<&Node> = self.root.iter().collect();
+        while let Some(node) = stack.pop() {
+            match node {
+                Node::Leaf { entries, .. } => found.extend(
+                    entries
+                        .iter()
+                        .filter(|&&i| self.boxes[i].intersection(area).is_some()),
+                ),
+                Node::Branch { children, .. } => stack.extend(
+                    children
+                        .iter()
+                        .filter(|child| child.bbox().intersection(area).is_some()),
+                ),
+            }
+        }
+        found.sort_unstable();
+        found
+    }
+
+    /// The other rectangles that overlap rectangle `index`.
+    pub fn overlapping(&self, index: usize) -> Vec<usize> {
+        let mut found = self.query(&self.boxes[index]);
+        found.retain(|&i| i != index);
+        found
+    }
+
+    /// The number of cells shared by rectangles `a` and `b`.
+    pub fn overlap_area(&self, a: usize, b: usize) -> u64 {
+        self.boxes[a]
+            .intersection(&self.boxes[b])
+            .map_or(0, |i| i.area() as u64)
+    }
+
+    /// For every rectangle, the rectangles it overlaps.
+    pub fn overlap_graph(&self) -> Vec<Vec<usize>> {
+        (0..self.len()).map(|i| self.overlapping(i)).collect()
+    }
+
+    /// Groups of rectangles connected through overlaps, each sorted, ordered by
+    /// their first member. A rectangle that overlaps nothing is a group of one.
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let graph = self.overlap_graph();
+        let mut seen = vec![false; self.len()];
+        let mut components = vec![];
+        for start in 0..self.len() {
+            if seen[start] {
+                continue;
+            }
+            seen[start] = true;
+            let mut component = vec![start];
+            let mut stack = vec![start];
+            while let Some(i) = stack.pop() {
+                for &j in &graph[i] {
+                    if !seen[j] {
+                        seen[j] = true;
+                        component.push(j);
+                        stack.push(j);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+        components
+    }
+}
+
+/// Sort-Tile-Recursive grouping of `items` into runs of at most `NODE_SIZE`.
+fn pack<T>(mut items: Vec<T>, bbox: impl Fn(&T) -> BBox<u32>) -> Vec<Vec<T>> {
+    let centre = |b: BBox<u32>| {
+        (
+            b.min.x as u64 + b.max.x as u64,
+            b.min.y as u64 + b.max.y as u64,
+        )
+    };
+    let leaves = items.len().div_ceil(NODE_SIZE);
+    let slices = (leaves as f64).sqrt().ceil() as usize;
+    let slice_len = slices * NODE_SIZE;
+    items.sort_by_key(|item| centre(bbox(item)).0);
+    let mut groups = vec![];
+    while !items.is_empty() {
+        let rest = items.split_off(slice_len.min(items.len()));
+        let mut slice = std::mem::replace(&mut items, rest);
+        slice.sort_by_key(|item| centre(bbox(item)).1);
+        while !slice.is_empty() {
+            let rest = slice.split_off(NODE_SIZE.min(slice.len()));
+            groups.push(std::mem::replace(&mut slice, rest));
+        }
+    }
+    groups
+}
+
+fn enclosing(boxes: impl Iterator<Item = BBox<u32>>) -> BBox<u32> {
+    boxes
+        .reduce(|mut a, b| {
+            a.include(b.min);
+            a.include(b.max);
+            a
+        })
+        .expect("nodes are never empty")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::boxes;
+    use aoc_core::grid::Point;
+
+    #[test]
+    fn query_matches_brute_force() {
+        for seed in 0..10 {
+            let boxes = boxes(200, seed);
+            let index = OverlapIndex::new(boxes.iter().copied());
+            for area in &boxes[..20] {
+                let expected: Vec<usize> = (0..boxes.len())
+                    .filter(|&i| boxes[i].intersection(area).is_some())
+                    .collect();
+                assert_eq!(index.query(area), expected, "seed {seed}");
+            }
+        }
+    }
+
+    #[test]
+    fn overlap_area_and_components() {
+        let boxes = [
+            BBox::from_size(Point::new(1, 3), 4, 4),
+            BBox::from_size(Point::new(3, 1), 4, 4),
+            BBox::from_size(Point::new(5, 5), 2, 2),
+            BBox::from_size(Point::new(20, 20), 2, 2),
+            BBox::from_size(Point::new(6, 6), 1, 1),
+        ];
+        let index = OverlapIndex::new(boxes);
+        assert_eq!(index.overlapping(0), vec![1]);
+        assert_eq!(index.overlap_area(0, 1), 4);
+        assert_eq!(index.overlap_area(0, 2), 0);
+        assert_eq!(index.overlapping(2), vec![4]);
+        assert_eq!(index.components(), vec![vec![0, 1], vec![2, 4], vec![3]]);
+    }
+
+    #[test]
+    fn empty_index() {
+        let index = OverlapIndex::new([]);
+        assert!(index.is_empty());
+        assert!(
+            index
+                .query(&BBox::new(Point::new(0, 0), Point::new(9, 9)))
+                .is_empty()
+        );
+        assert!(index.components().is_empty());
+    }
+}
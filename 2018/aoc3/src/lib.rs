@@ -0,0 +1,251 @@
+use aoc_core::grid::{BBox, Point};
+use aoc_core::{Answer, LineError, Solution, parse};
+use coverage::Coverage;
+use index::OverlapIndex;
+use regex::Regex;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+pub mod coverage;
+pub mod index;
+
+#[cfg(test)]
+mod fixtures;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed = Vec<Claim>;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Parsed> {
+        parse::lines(input)
+    }
+
+    fn part1(&self, claims: &Self::Parsed) -> aoc_core::Result<Answer> {
+        part1(claims)
+    }
+
+    fn part2(&self, claims: &Self::Parsed) -> aoc_core::Result<Answer> {
+        part2(claims)
+    }
+}
+
+/// Vertical distances are measured downward, so the top edge has a lower value than the bottom edge
+#[derive(Debug)]
+pub struct Claim {
+    id: u32,
+    left_edge: u32,
+    top_edge: u32,
+    width: u32,
+    height: u32,
+}
+
+static RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^#(?<id>\d+) @ (?<left_edge>\d+),(?<top_edge>\d+): (?<width>\d+)x(?<height>\d+)$")
+        .expect("Regex compiles")
+});
+
+impl FromStr for Claim {
+    type Err = LineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cap = RE
+            .captures(s)
+            .ok_or_else(|| LineError::new("expected a claim like `#1 @ 1,3: 4x4`"))?;
+        Claim::from_capture(cap)
+    }
+}
+
+impl Claim {
+    fn from_capture(c: regex::Captures) -> Result<Self, LineError> {
+        let field = |name: &str| -> Result<u32, LineError> {
+            let m = c.name(name).expect("all claim fields are captured");
+            m.as_str()
+                .parse()
+                .map_err(|e: std::num::ParseIntError| LineError::at(m.range(), e.to_string()))
+        };
+        let claim = Claim {
+            id: field("id")?,
+            left_edge: field("left_edge")?,
+            top_edge: field("top_edge")?,
+            width: field("width")?,
+            height: field("height")?,
+        };
+        for (name, edge, size) in [
+            ("width", claim.left_edge, claim.width),
+            ("height", claim.top_edge, claim.height),
+        ] {
+            let m = c.name(name).expect("all claim fields are captured");
+            if size == 0 {
+                return Err(LineError::at(
+                    m.range(),
+                    format!("claim {name} must be positive"),
+                ));
+            }
+            if edge.checked_add(size).is_none() {
+                return Err(LineError::at(
+                    m.range(),
+                    format!("claim {name} reaches past {}", u32::MAX),
+                ));
+            }
+        }
+        Ok(claim)
+    }
+
+    fn bounding_box(&self) -> BBox<u32> {
+        BBox::from_size(
+            Point::new(self.left_edge, self.top_edge),
+            self.width,
+            self.height,
+        )
+    }
+}
+
+fn part1(claims: &[Claim]) -> aoc_core::Result<Answer> {
+    let coverage = Coverage::new(claims.iter().map(Claim::bounding_box));
+    Ok(coverage.area_at_least(2).into())
+}
+
+fn part2(claims: &[Claim]) -> aoc_core::Result<Answer> {
+    let fabric = Fabric::new(claims);
+    let undisputed = fabric.undisputed().ok_or("no undisputed claim")?;
+    Ok(undisputed.into())
+}
+
+/// Overlap questions about claims, asked by claim id.
+pub struct Fabric {
+    ids: Vec<u32>,
+    /// Where each id sits in `ids`.
+    positions: HashMap<u32, usize>,
+    index: OverlapIndex,
+}
+
+impl Fabric {
+    pub fn new(claims: &[Claim]) -> Self {
+        let ids: Vec<u32> = claims.iter().map(|c| c.id).collect();
+        let mut positions = HashMap::with_capacity(ids.len());
+        for (position, &id) in ids.iter().enumerate() {
+            positions.entry(id).or_insert(position);
+        }
+        Fabric {
+            ids,
+            positions,
+            index: OverlapIndex::new(claims.iter().map(Claim::bounding_box)),
+        }
+    }
+
+    fn position(&self, id: u32) -> Option<usize> {
+        self.positions.get(&id).copied()
+    }
+
+    fn to_ids(&self, positions: Vec<usize>) -> Vec<u32> {
+        positions.into_iter().map(|i| self.ids[i]).collect()
+    }
+
+    /// The claims that share fabric with claim `id`, or `None` for an unknown id.
+    pub fn overlapping(&self, id: u32) -> Option<Vec<u32>> {
+        Some(self.to_ids(self.index.overlapping(self.position(id)?)))
+    }
+
+    /// The square inches shared by claims `a` and `b`.
+    pub fn overlap_area(&self, a: u32, b: u32) -> Option<u64> {
+        Some(
+            self.index
+                .overlap_area(self.position(a)?, self.position(b)?),
+        )
+    }
+
+    /// Groups of claims connected through overlaps.
+    pub fn components(&self) -> Vec<Vec<u32>> {
+        let components = self.index.components();
+        components.into_iter().map(|c| self.to_ids(c)).collect()
+    }
+
+    /// The first claim that overlaps no other.
+    pub fn undisputed(&self) -> Option<u32> {
+        (0..self.ids.len())
+            .find(|&i| self.index.overlapping(i).is_empty())
+            .map(|i| self.ids[i])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn make_claims() -> Vec<Claim> {
+        vec![
+            Claim {
+                id: 0,
+                left_edge: 2,
+                top_edge: 2,
+                width: 2,
+                height: 3,
+            },
+            // o o o o
+            // o o o o
+            // o o x x
+            // o o x x
+            // o o x x
+            Claim {
+                id: 1,
+                left_edge: 3,
+                top_edge: 1,
+                width: 2,
+                height: 2,
+            },
+            // o o o o o
+            // o o o x x
+            // o o o x x
+        ]
+    }
+
+    #[test]
+    fn malformed_claim_is_reported() {
+        let input = "#1 @ 1,3: 4x4\n#2 @ 3,1: 4x\n";
+        let Err(aoc_core::Error::Parse(e)) = Day3.parse(input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(e.line, 2);
+        let input = "#1 @ 1,3: 0x4";
+        let Err(aoc_core::Error::Parse(e)) = Day3.parse(input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.column, e.width), (11, 1));
+        let input = "#1 @ 4294967295,0: 2x2";
+        let Err(aoc_core::Error::Parse(e)) = Day3.parse(input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.column, e.width), (20, 1));
+    }
+
+    #[test]
+    fn bbox_from_claim() {
+        let claims = make_claims();
+        let bbox = claims[0].bounding_box();
+        assert_eq!(Point::new(2, 2), bbox.min);
+        assert_eq!(Point::new(3, 4), bbox.max);
+    }
+
+    #[test]
+    fn overlap_of_example_claims() {
+        let coverage = Coverage::new(make_claims().iter().map(Claim::bounding_box));
+        assert_eq!(1, coverage.area_at_least(2));
+        assert!(coverage.isolated().is_empty());
+    }
+
+    #[test]
+    fn fabric_queries_by_id() -> aoc_core::Result<()> {
+        let claims = Day3.parse(include_str!("../input/example1.txt"))?;
+        let fabric = Fabric::new(&claims);
+        assert_eq!(fabric.overlapping(1), Some(vec![2]));
+        assert_eq!(fabric.overlapping(3), Some(vec![]));
+        assert_eq!(fabric.overlapping(9), None);
+        assert_eq!(fabric.overlap_area(1, 2), Some(4));
+        assert_eq!(fabric.components(), vec![vec![1, 2], vec![3]]);
+        assert_eq!(fabric.undisputed(), Some(3));
+        let coverage = Coverage::new(claims.iter().map(Claim::bounding_box));
+        assert_eq!(coverage.isolated(), vec![2]);
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+//! Prints the sleep chart for a guard log, optionally followed by a heatmap.
+//!
+//! ```sh
+//! cargo run -p aoc2018-day4 --example chart -- [--heatmap text|csv] [log]
+//! ```
+//!
+//! Without a path the day's puzzle input is used.
+
+use aoc_core::{Result, Solution};
+use aoc2018_day4::Day4;
+use aoc2018_day4::chart::{Heatmap, render_chart};
+use std::path::Path;
+use std::process::ExitCode;
+
+enum HeatmapFormat {
+    Text,
+    Csv,
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<()> {
+    let mut heatmap = None;
+    let mut path = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--heatmap" => {
+                heatmap = match args.next().as_deref() {
+                    Some("text") => Some(HeatmapFormat::Text),
+                    Some("csv") => Some(HeatmapFormat::Csv),
+                    _ => return Err("--heatmap takes `text` or `csv`".into()),
+                }
+            }
+            _ => path = Some(arg),
+        }
+    }
+    let input = match path {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => aoc_core::input::read_input(Path::new(env!("CARGO_MANIFEST_DIR")))?,
+    };
+    let shifts = aoc2018_day4::shifts(&Day4.parse(&input)?)?;
+    print!("{}", render_chart(&shifts));
+    match heatmap {
+        Some(HeatmapFormat::Text) => print!("\n{}", Heatmap::new(&shifts).to_text()),
+        Some(HeatmapFormat::Csv) => print!("\n{}", Heatmap::new(&shifts).to_csv()),
+        None => {}
+    }
+    Ok(())
+}
@@ -0,0 +1,177 @@
+//! Text renderings of the guard log: the puzzle's per-shift chart and a
+//! per-guard heatmap of the midnight hour.
+
+use crate::{GuardId, Shift, Window, minute_counts, naps_by_guard};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// The charts show the same window the puzzle's answers count.
+const WINDOW: Window = Window::MidnightHour;
+const MINUTES: usize = WINDOW.minutes();
+
+/// Whether the guard was asleep during each minute of the shift's window.
+fn asleep_minutes(shift: &Shift) -> [bool; MINUTES] {
+    let mut asleep = [false; MINUTES];
+    for part in shift.naps.iter().flat_map(|nap| WINDOW.split(nap)) {
+        asleep[part].fill(true);
+    }
+    asleep
+}
+
+/// The two header rows numbering the minutes `00` to `59`, indented by `indent`.
+fn minute_header(indent: usize) -> String {
+    let tens: String = (0..MINUTES)
+        .map(|m| char::from(b'0' + m as u8 / 10))
+        .collect();
+    let units: String = (0..MINUTES)
+        .map(|m| char::from(b'0' + m as u8 % 10))
+        .collect();
+    format!("{:indent$}{tens}\n{:indent$}{units}\n", "", "")
+}
+
+/// The chart from the puzzle text: one row per shift, `#` for asleep and `.`
+/// for awake in each minute of the midnight hour.
+///
+/// ```text
+/// Date   ID   Minute
+///             000000000011111111112222222222333333333344444444445555555555
+///             012345678901234567890123456789012345678901234567890123456789
+/// 11-01  #10  .....####################.....#########################.....
+/// ```
+pub fn render_chart(shifts: &[Shift]) -> String {
+    let width = shifts
+        .iter()
+        .map(|s| format!("#{}", s.guard).len())
+        .max()
+        .unwrap_or(0)
+        .max("ID".len());
+    let mut chart = format!("Date   {:width$}  Minute\n", "ID");
+    chart += &minute_header(7 + width + 2);
+    for shift in shifts {
+        let marks: String = asleep_minutes(shift)
+            .iter()
+            .map(|&asleep| if asleep { '#' } else { '.' })
+            .collect();
+        let id = format!("#{}", shift.guard);
+        let date = WINDOW.day_of(shift.start).format("%m-%d");
+        writeln!(chart, "{date}  {id:width$}  {marks}").expect("writing to a String");
+    }
+    chart
+}
+
+/// For each guard, on how many days they were asleep during each minute of
+/// the midnight hour.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heatmap {
+    pub guards: BTreeMap<GuardId, [u32; MINUTES]>,
+}
+
+impl Heatmap {
+    pub fn new(shifts: &[Shift]) -> Self {
+        let guards = naps_by_guard(shifts)
+            .into_iter()
+            .map(|(guard, naps)| {
+                let counts = minute_counts(&naps, WINDOW)
+                    .try_into()
+                    .expect("one count per minute of the window");
+                (guard, counts)
+            })
+            .collect();
+        Heatmap { guards }
+    }
+
+    /// A `guard,0,...,59` header and one row of counts per guard.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("guard");
+        for minute in 0..MINUTES {
+            write!(csv, ",{minute}").expect("writing to a String");
+        }
+        csv.push('\n');
+        for (guard, counts) in &self.guards {
+            write!(csv, "{guard}").expect("writing to a String");
+            for count in counts {
+                write!(csv, ",{count}").expect("writing to a String");
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// One row per guard, shading each minute from ` ` (never asleep) to `@`
+    /// (asleep on the most days of any guard and minute).
+    pub fn to_text(&self) -> String {
+        const SHADES: &[u8] = b" .:-=+*#%@";
+        let max = self.guards.values().flatten().copied().max().unwrap_or(0);
+        let width = self
+            .guards
+            .keys()
+            .map(|g| format!("#{g}").len())
+            .max()
+            .unwrap_or(0);
+        let mut text = minute_header(width + 2);
+        for (guard, counts) in &self.guards {
+            let shades: String = counts
+                .iter()
+                .map(|&count| {
+                    let top = SHADES.len() - 1;
+                    let level = match count {
+                        0 => 0,
+                        n if n == max => top,
+                        n => 1 + (n - 1) as usize * (top - 1) / max as usize,
+                    };
+                    char::from(SHADES[level])
+                })
+                .collect();
+            let id = format!("#{guard}");
+            writeln!(text, "{id:width$}  {}", shades.trim_end()).expect("writing to a String");
+        }
+        writeln!(text, "`@` = asleep on {max} days").expect("writing to a String");
+        text
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Day4, shifts};
+    use aoc_core::Solution;
+
+    fn example() -> Vec<Shift> {
+        let records = Day4
+            .parse(include_str!("../input/example1.txt"))
+            .expect("example parses");
+        shifts(&records).expect("example is a valid log")
+    }
+
+    #[test]
+    fn chart_matches_puzzle() {
+        let expected = "\
+Date   ID   Minute
+            000000000011111111112222222222333333333344444444445555555555
+            012345678901234567890123456789012345678901234567890123456789
+11-01  #10  .....####################.....#########################.....
+11-02  #99  ........................................##########..........
+11-03  #10  ........................#####...............................
+11-04  #99  ....................................##########..............
+11-05  #99  .............................................##########.....
+";
+        assert_eq!(render_chart(&example()), expected);
+    }
+
+    #[test]
+    fn heatmap_counts_days() {
+        let heatmap = Heatmap::new(&example());
+        assert_eq!(heatmap.guards[&10][24], 2);
+        assert_eq!(heatmap.guards[&99][45], 3);
+        assert_eq!(heatmap.guards[&99][0], 0);
+        let csv = heatmap.to_csv();
+        let mut lines = csv.lines();
+        assert!(lines.next().unwrap().starts_with("guard,0,1,2,"));
+        assert!(lines.next().unwrap().starts_with("10,0,0,0,0,0,1,"));
+        let text = heatmap.to_text();
+        assert!(text.contains("#99  "));
+        assert!(text.ends_with("`@` = asleep on 3 days\n"));
+        let row99 = text.lines().find(|l| l.starts_with("#99")).unwrap();
+        assert_eq!(&row99[5 + 45..5 + 46], "@");
+    }
+}
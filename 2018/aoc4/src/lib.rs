@@ -0,0 +1,564 @@
+use aoc_core::{Answer, LineError, Solution, parse};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeDelta, Utc};
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::sync::LazyLock;
+
+pub mod chart;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed = Vec<Record>;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Parsed> {
+        let mut records: Vec<Record> = parse::lines(input)?;
+        sort_records(&mut records);
+        Ok(records)
+    }
+
+    fn part1(&self, records: &Self::Parsed) -> aoc_core::Result<Answer> {
+        part1(records)
+    }
+
+    fn part2(&self, records: &Self::Parsed) -> aoc_core::Result<Answer> {
+        part2(records)
+    }
+}
+
+pub type GuardId = u32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    BeginShift(GuardId),
+    FallAsleep,
+    WakeUp,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub datetime: DateTime<Utc>,
+    pub event: Event,
+}
+
+static RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\[(?<datetime>\d\d\d\d-\d\d-\d\d \d\d:\d\d)\] (?:.*(?<sleep>asleep|wakes)|Guard #(?<id>\d+))")
+        .expect("regex compiles")
+});
+
+impl std::str::FromStr for Record {
+    type Err = LineError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cap = RE.captures(s).ok_or_else(|| {
+            LineError::new("expected `[YYYY-MM-DD hh:mm] Guard #<id> begins shift`, `falls asleep` or `wakes up`")
+        })?;
+        let event = match (cap.name("id"), cap.name("sleep")) {
+            (Some(m), _) => Event::BeginShift(
+                m.as_str()
+                    .parse::<GuardId>()
+                    .map_err(|e| LineError::at(m.range(), e.to_string()))?,
+            ),
+            (None, Some(m)) if m.as_str() == "asleep" => Event::FallAsleep,
+            (None, _) => Event::WakeUp,
+        };
+        let m = cap.name("datetime").expect("datetime is not optional");
+        let naive = NaiveDateTime::parse_from_str(m.as_str(), "%Y-%m-%d %H:%M")
+            .map_err(|e| LineError::at(m.range(), e.to_string()))?;
+        let datetime = DateTime::<Utc>::from_naive_utc_and_offset(naive, Utc);
+        Ok(Self { datetime, event })
+    }
+}
+
+fn sort_records(records: &mut [Record]) {
+    records.sort_by_key(|r| r.datetime);
+}
+
+/// A stretch of sleep from the minute a guard fell asleep up to, but not
+/// including, the minute they woke.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Nap {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl Nap {
+    pub fn minutes(&self) -> i64 {
+        self.end.signed_duration_since(self.start).num_minutes()
+    }
+}
+
+/// One guard's time on duty and the naps taken during it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shift {
+    pub guard: GuardId,
+    pub start: DateTime<Utc>,
+    pub naps: Vec<Nap>,
+}
+
+/// A sorted log that cannot have happened.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum LogError {
+    #[error("{at}: the log starts before any guard begins a shift")]
+    NoGuard { at: DateTime<Utc> },
+    #[error("{at}: guard #{guard} wakes up without having fallen asleep")]
+    WakeWithoutSleep { guard: GuardId, at: DateTime<Utc> },
+    #[error("{at}: guard #{guard} falls asleep while already asleep")]
+    AlreadyAsleep { guard: GuardId, at: DateTime<Utc> },
+    #[error("{at}: guard #{guard} is still asleep when the shift ends")]
+    StillAsleep { guard: GuardId, at: DateTime<Utc> },
+}
+
+impl From<LogError> for aoc_core::Error {
+    fn from(error: LogError) -> Self {
+        aoc_core::Error::other(error)
+    }
+}
+
+/// Replays sorted records, grouping the naps into shifts. A guard must be on
+/// duty before anyone sleeps, and must be awake again before the next shift
+/// begins or the log ends.
+pub fn shifts(records: &[Record]) -> Result<Vec<Shift>, LogError> {
+    let mut shifts: Vec<Shift> = vec![];
+    let mut asleep_since = None;
+    for &Record {
+        datetime: at,
+        event,
+    } in records
+    {
+        let Some(shift) = shifts.last_mut() else {
+            match event {
+                Event::BeginShift(guard) => {
+                    shifts.push(Shift {
+                        guard,
+                        start: at,
+                        naps: vec![],
+                    });
+                    continue;
+                }
+                _ => return Err(LogError::NoGuard { at }),
+            }
+        };
+        let guard = shift.guard;
+        match (event, asleep_since) {
+            (Event::BeginShift(_), Some(_)) => return Err(LogError::StillAsleep { guard, at }),
+            (Event::BeginShift(guard), None) => shifts.push(Shift {
+                guard,
+                start: at,
+                naps: vec![],
+            }),
+            (Event::FallAsleep, Some(_)) => return Err(LogError::AlreadyAsleep { guard, at }),
+            (Event::FallAsleep, None) => asleep_since = Some(at),
+            (Event::WakeUp, None) => return Err(LogError::WakeWithoutSleep { guard, at }),
+            (Event::WakeUp, Some(start)) => {
+                shift.naps.push(Nap { start, end: at });
+                asleep_since = None;
+            }
+        }
+    }
+    if let (Some(shift), Some(_), Some(last)) = (shifts.last(), asleep_since, records.last()) {
+        return Err(LogError::StillAsleep {
+            guard: shift.guard,
+            at: last.datetime,
+        });
+    }
+    Ok(shifts)
+}
+
+/// Every nap of every shift, grouped by guard.
+fn naps_by_guard(shifts: &[Shift]) -> BTreeMap<GuardId, Vec<Nap>> {
+    let mut naps = BTreeMap::<GuardId, Vec<Nap>>::new();
+    for shift in shifts {
+        naps.entry(shift.guard)
+            .or_default()
+            .extend(shift.naps.iter().copied());
+    }
+    naps
+}
+
+/// The stretch of each day in which sleep is counted. Naps are clipped to it
+/// and split where they cross from one day's window into the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Window {
+    /// 00:00 to 01:00, the only hour the puzzle counts.
+    #[default]
+    MidnightHour,
+    /// 00:00 to 24:00.
+    Day,
+}
+
+impl Window {
+    /// The number of minutes in the window.
+    pub const fn minutes(self) -> usize {
+        match self {
+            Window::MidnightHour => 60,
+            Window::Day => 24 * 60,
+        }
+    }
+
+    fn open(date: NaiveDate) -> DateTime<Utc> {
+        date.and_hms_opt(0, 0, 0)
+            .expect("midnight exists")
+            .and_utc()
+    }
+
+    fn length(self) -> TimeDelta {
+        TimeDelta::minutes(self.minutes() as i64)
+    }
+
+    /// The day whose window a shift starting at `start` covers: the first
+    /// window that has not closed by then.
+    pub fn day_of(self, start: DateTime<Utc>) -> NaiveDate {
+        let date = start.date_naive();
+        if start < Window::open(date) + self.length() {
+            date
+        } else {
+            date.succ_opt()
+                .expect("dates in the log are far from the end of time")
+        }
+    }
+
+    /// The parts of `nap` inside each day's window, as minute offsets from
+    /// the start of that window.
+    pub fn split(self, nap: &Nap) -> Vec<Range<usize>> {
+        let length = self.length();
+        let mut parts = vec![];
+        let mut date = nap.start.date_naive();
+        while date <= nap.end.date_naive() {
+            let open = Window::open(date);
+            let start = nap.start.max(open);
+            let end = nap.end.min(open + length);
+            if start < end {
+                let offset =
+                    |at: DateTime<Utc>| at.signed_duration_since(open).num_minutes() as usize;
+                parts.push(offset(start)..offset(end));
+            }
+            date = date
+                .succ_opt()
+                .expect("dates in the log are far from the end of time");
+        }
+        parts
+    }
+}
+
+/// How many of `naps` cover each minute of `window`.
+fn minute_counts(naps: &[Nap], window: Window) -> Vec<u32> {
+    let mut counts = vec![0; window.minutes()];
+    for part in naps.iter().flat_map(|nap| window.split(nap)) {
+        counts[part].iter_mut().for_each(|count| *count += 1);
+    }
+    counts
+}
+
+/// The first minute with the highest count, and that count.
+fn sleepiest_minute(counts: &[u32]) -> (usize, u32) {
+    counts
+        .iter()
+        .enumerate()
+        .fold((0, 0), |best, (minute, &count)| {
+            if count > best.1 {
+                (minute, count)
+            } else {
+                best
+            }
+        })
+}
+
+/// Strategy 1: the guard asleep the most minutes within `window`, and the
+/// minute (counted from the window's start) they were most often asleep.
+pub fn strategy1(shifts: &[Shift], window: Window) -> Option<(GuardId, usize)> {
+    let mut best: Option<(GuardId, u32, usize)> = None;
+    for (id, naps) in naps_by_guard(shifts) {
+        let counts = minute_counts(&naps, window);
+        let total = counts.iter().sum();
+        if total > 0 && best.is_none_or(|(_, most, _)| total > most) {
+            best = Some((id, total, sleepiest_minute(&counts).0));
+        }
+    }
+    best.map(|(id, _, minute)| (id, minute))
+}
+
+/// Strategy 2: the guard and minute of `window` with the most days asleep.
+pub fn strategy2(shifts: &[Shift], window: Window) -> Option<(GuardId, usize)> {
+    let mut best: Option<(GuardId, usize, u32)> = None;
+    for (id, naps) in naps_by_guard(shifts) {
+        let (minute, count) = sleepiest_minute(&minute_counts(&naps, window));
+        if count > 0 && best.is_none_or(|(_, _, most)| count > most) {
+            best = Some((id, minute, count));
+        }
+    }
+    best.map(|(id, minute, _)| (id, minute))
+}
+
+fn part1(records: &[Record]) -> aoc_core::Result<Answer> {
+    let (id, minute) =
+        strategy1(&shifts(records)?, Window::MidnightHour).ok_or("no guard ever slept")?;
+    Ok((id as usize * minute).into())
+}
+
+fn part2(records: &[Record]) -> aoc_core::Result<Answer> {
+    let (id, minute) =
+        strategy2(&shifts(records)?, Window::MidnightHour).ok_or("no guard ever slept")?;
+    Ok((id as usize * minute).into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_core::testing::Rng;
+    use chrono::{NaiveTime, Timelike};
+    use std::cmp::Reverse;
+    const INPUT_LINES: &str = "[1518-04-16 00:46] falls asleep
+    [1518-10-31 00:46] wakes up
+    [1518-10-14 00:02] Guard #2459 begins shift";
+    #[test]
+    fn parse_sleep_record_test() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let line = INPUT_LINES.lines().next().unwrap();
+        let record: Record = line.parse()?;
+        assert_eq!(record.event, Event::FallAsleep);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_id_record_test() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let line = INPUT_LINES.lines().nth(2).unwrap();
+        let record: Record = line.parse()?;
+        assert_eq!(record.event, Event::BeginShift(2459));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_wake_record_test() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let line = INPUT_LINES.lines().nth(1).unwrap();
+        let record: Record = line.parse()?;
+        assert_eq!(record.event, Event::WakeUp);
+        Ok(())
+    }
+
+    #[test]
+    fn sort_records_test() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let mut records = Vec::<Record>::new();
+        for line in INPUT_LINES.lines() {
+            records.push(line.parse()?)
+        }
+        sort_records(&mut records);
+        assert_eq!(
+            records[0].datetime,
+            DateTime::<Utc>::from_naive_utc_and_offset(
+                NaiveDateTime::new(
+                    NaiveDate::from_ymd_opt(1518, 4, 16).expect("valid date"),
+                    NaiveTime::from_hms_opt(0, 46, 0).expect("valide hour")
+                ),
+                Utc
+            )
+        );
+        assert_eq!(
+            records[1].datetime,
+            DateTime::<Utc>::from_naive_utc_and_offset(
+                NaiveDateTime::new(
+                    NaiveDate::from_ymd_opt(1518, 10, 14).expect("valid date"),
+                    NaiveTime::from_hms_opt(0, 2, 0).expect("valid time"),
+                ),
+                Utc
+            )
+        );
+        Ok(())
+    }
+
+    fn records(log: &str) -> Vec<Record> {
+        Day4.parse(log).expect("log parses")
+    }
+
+    #[test]
+    fn shifts_from_example() -> aoc_core::Result<()> {
+        let shifts = shifts(&records(include_str!("../input/example1.txt")))?;
+        let guards: Vec<GuardId> = shifts.iter().map(|s| s.guard).collect();
+        assert_eq!(guards, [10, 99, 10, 99, 99]);
+        let minutes: Vec<i64> = shifts[0].naps.iter().map(Nap::minutes).collect();
+        assert_eq!(minutes, [20, 25]);
+        assert!(shifts[3].naps.len() == 1 && shifts[4].naps.len() == 1);
+        Ok(())
+    }
+
+    #[test]
+    fn impossible_logs_are_rejected() {
+        let at = |minute: u32| {
+            let time = NaiveTime::from_hms_opt(0, minute, 0).expect("valid time");
+            let date = NaiveDate::from_ymd_opt(1518, 11, 1).expect("valid date");
+            DateTime::<Utc>::from_naive_utc_and_offset(NaiveDateTime::new(date, time), Utc)
+        };
+        let check = |log: &str, expected: LogError| {
+            assert_eq!(shifts(&records(log)), Err(expected));
+        };
+        check(
+            "[1518-11-01 00:05] falls asleep",
+            LogError::NoGuard { at: at(5) },
+        );
+        check(
+            "[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:05] wakes up",
+            LogError::WakeWithoutSleep {
+                guard: 10,
+                at: at(5),
+            },
+        );
+        check(
+            "[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:05] falls asleep\n[1518-11-01 00:07] falls asleep",
+            LogError::AlreadyAsleep {
+                guard: 10,
+                at: at(7),
+            },
+        );
+        check(
+            "[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:05] falls asleep\n[1518-11-01 00:09] Guard #3 begins shift",
+            LogError::StillAsleep {
+                guard: 10,
+                at: at(9),
+            },
+        );
+        check(
+            "[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:05] falls asleep",
+            LogError::StillAsleep {
+                guard: 10,
+                at: at(5),
+            },
+        );
+    }
+
+    #[test]
+    fn part1_and_part2_example() -> aoc_core::Result<()> {
+        let records = records(include_str!("../input/example1.txt"));
+        assert_eq!(part1(&records)?, Answer::Int(240));
+        assert_eq!(part2(&records)?, Answer::Int(4455));
+        Ok(())
+    }
+
+    fn at(date: &str) -> DateTime<Utc> {
+        NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M")
+            .expect("valid datetime")
+            .and_utc()
+    }
+
+    #[test]
+    fn naps_split_at_window_edges() {
+        let split = |window: Window, start, end| {
+            let nap = Nap {
+                start: at(start),
+                end: at(end),
+            };
+            window
+                .split(&nap)
+                .into_iter()
+                .map(|r| (r.start, r.end))
+                .collect::<Vec<_>>()
+        };
+        let (start, end) = ("1518-11-01 00:50", "1518-11-01 01:10");
+        assert_eq!(split(Window::MidnightHour, start, end), [(50, 60)]);
+        assert_eq!(split(Window::Day, start, end), [(50, 70)]);
+        let (start, end) = ("1518-11-01 23:50", "1518-11-02 00:10");
+        assert_eq!(split(Window::MidnightHour, start, end), [(0, 10)]);
+        assert_eq!(split(Window::Day, start, end), [(1430, 1440), (0, 10)]);
+    }
+
+    #[test]
+    fn evening_shifts_cover_the_next_midnight_hour() {
+        let date = |d| NaiveDate::parse_from_str(d, "%Y-%m-%d").expect("valid date");
+        let late = at("1518-11-01 23:58");
+        assert_eq!(Window::MidnightHour.day_of(late), date("1518-11-02"));
+        assert_eq!(Window::Day.day_of(late), date("1518-11-01"));
+        let early = at("1518-11-02 00:02");
+        assert_eq!(Window::MidnightHour.day_of(early), date("1518-11-02"));
+    }
+
+    /// `(guard, start, end)`.
+    type GuardNap = (GuardId, DateTime<Utc>, DateTime<Utc>);
+
+    /// A log of `days` shifts, many starting before midnight and with naps that
+    /// run past 01:00, in reverse order, along with every nap as
+    /// `(guard, start, end)`.
+    fn generated_log(seed: u64, days: i64) -> (String, Vec<GuardNap>) {
+        let mut rng = Rng::new(seed);
+        let mut next = |bound: i64| rng.below(bound as u64) as i64;
+        let mut lines = vec![];
+        let mut naps = vec![];
+        for day in 0..days {
+            let guard = [7, 10, 99, 2459][next(4) as usize];
+            let midnight = at("1518-03-01 00:00") + TimeDelta::days(day);
+            let mut cursor = midnight + TimeDelta::minutes(next(25) - 20);
+            lines.push(format!(
+                "[{}] Guard #{guard} begins shift",
+                cursor.format("%Y-%m-%d %H:%M")
+            ));
+            for _ in 0..next(4) {
+                let start = cursor + TimeDelta::minutes(1 + next(30));
+                let end = start + TimeDelta::minutes(1 + next(40));
+                lines.push(format!("[{}] falls asleep", start.format("%Y-%m-%d %H:%M")));
+                lines.push(format!("[{}] wakes up", end.format("%Y-%m-%d %H:%M")));
+                naps.push((guard, start, end));
+                cursor = end;
+            }
+        }
+        lines.reverse();
+        (lines.join("\n"), naps)
+    }
+
+    /// Counts every minute of every nap one at a time.
+    fn brute_force_counts(naps: &[GuardNap], window: Window) -> BTreeMap<GuardId, Vec<u32>> {
+        let mut counts = BTreeMap::<GuardId, Vec<u32>>::new();
+        for &(guard, start, end) in naps {
+            let counts = counts.entry(guard).or_insert(vec![0; window.minutes()]);
+            let mut minute = start;
+            while minute < end {
+                let slot = (minute.hour() * 60 + minute.minute()) as usize;
+                if slot < window.minutes() {
+                    counts[slot] += 1;
+                }
+                minute += TimeDelta::minutes(1);
+            }
+        }
+        counts
+    }
+
+    #[test]
+    fn generated_logs_match_brute_force() -> aoc_core::Result<()> {
+        for seed in 0..10 {
+            let (log, naps) = generated_log(seed, 40);
+            let shifts = shifts(&Day4.parse(&log)?)?;
+            for window in [Window::MidnightHour, Window::Day] {
+                let counts = brute_force_counts(&naps, window);
+                // Ties go to the lowest guard id, then the earliest minute.
+                let expected1 = counts
+                    .iter()
+                    .map(|(&guard, counts)| (guard, counts.iter().sum::<u32>()))
+                    .filter(|&(_, total)| total > 0)
+                    .max_by_key(|&(guard, total)| (total, Reverse(guard)))
+                    .map(|(guard, _)| {
+                        let (minute, _) = counts[&guard]
+                            .iter()
+                            .enumerate()
+                            .max_by_key(|&(minute, &days)| (days, Reverse(minute)))
+                            .expect("the window has minutes");
+                        (guard, minute)
+                    });
+                assert_eq!(
+                    strategy1(&shifts, window),
+                    expected1,
+                    "seed {seed} {window:?}"
+                );
+                let expected2 = counts
+                    .iter()
+                    .flat_map(|(&guard, counts)| {
+                        (0..counts.len()).map(move |minute| (guard, minute, counts[minute]))
+                    })
+                    .filter(|&(_, _, days)| days > 0)
+                    .max_by_key(|&(guard, minute, days)| (days, Reverse(guard), Reverse(minute)))
+                    .map(|(guard, minute, _)| (guard, minute));
+                assert_eq!(
+                    strategy2(&shifts, window),
+                    expected2,
+                    "seed {seed} {window:?}"
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+//! Prints the polymer length left after removing each unit type, and which
+//! removals are best.
+//!
+//! ```sh
+//! cargo run -p aoc2018-day5 --example removals -- [--jobs <n>] [polymer]
+//! ```
+//!
+//! Without a path the day's puzzle input is used.
+
+use aoc_core::Result;
+use aoc2018_day5::removal_report;
+use std::path::Path;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<()> {
+    let mut path = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--jobs" => {
+                let jobs = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .filter(|&n| n > 0)
+                    .ok_or("--jobs takes a positive number")?;
+                aoc_core::parallel::set_jobs(jobs);
+            }
+            _ => path = Some(arg),
+        }
+    }
+    let input = match path {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => aoc_core::input::read_input(Path::new(env!("CARGO_MANIFEST_DIR")))?,
+    };
+    let report = removal_report(&input);
+    print!("{report}");
+    let optimal: Vec<String> = report.optimal().iter().map(char::to_string).collect();
+    println!(
+        "\nremoving {} leaves {} units",
+        optimal.join(" or "),
+        report.shortest()
+    );
+    Ok(())
+}
@@ -0,0 +1,242 @@
+use aoc_core::{Answer, Result, Solution, parallel};
+use std::fmt;
+use std::io::{self, BufRead};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(&self, polymer: &Self::Parsed) -> Result<Answer> {
+        Ok(part1_result(polymer).len().into())
+    }
+
+    fn part2(&self, polymer: &Self::Parsed) -> Result<Answer> {
+        Ok(part2(polymer).into())
+    }
+}
+
+fn part1_result(s: &str) -> Vec<u8> {
+    react_reader(s.as_bytes())
+        .expect("reading from a slice cannot fail")
+        .into_units()
+}
+
+fn react(protein: &[u8]) -> Vec<u8> {
+    let mut reactor = Reactor::default();
+    reactor.feed(protein);
+    reactor.into_units()
+}
+
+/// Reacts a polymer of any size read from `reader`, keeping only the units that
+/// have not reacted yet. ASCII whitespace, such as a trailing newline, is skipped.
+pub fn react_reader<R: BufRead>(mut reader: R) -> io::Result<Reactor> {
+    let mut reactor = Reactor::default();
+    loop {
+        let chunk = reader.fill_buf()?;
+        if chunk.is_empty() {
+            return Ok(reactor);
+        }
+        let read = chunk.len();
+        reactor.feed(chunk);
+        reader.consume(read);
+    }
+}
+
+/// The units left after reacting everything fed so far, as a stack: a new unit
+/// either annihilates the top or is pushed onto it.
+#[derive(Debug, Default, Clone)]
+pub struct Reactor {
+    stack: Vec<u8>,
+}
+
+impl Reactor {
+    pub fn push(&mut self, unit: u8) {
+        if unit.is_ascii_whitespace() {
+            return;
+        }
+        match self.stack.last() {
+            Some(&top) if annihilate(unit, top) => {
+                self.stack.pop();
+            }
+            _ => self.stack.push(unit),
+        }
+    }
+
+    pub fn feed(&mut self, units: &[u8]) {
+        units.iter().for_each(|&unit| self.push(unit));
+    }
+
+    /// The length of the polymer after reacting.
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    pub fn into_units(self) -> Vec<u8> {
+        self.stack
+    }
+}
+
+fn annihilate(c1: u8, c2: u8) -> bool {
+    if c1 > c2 {
+        c1 - c2 == 32
+    } else {
+        c2 - c1 == 32
+    }
+}
+
+fn part2(input: &str) -> usize {
+    removal_report(input).shortest()
+}
+
+/// The length left after removing each unit type and reacting again.
+///
+/// Every experiment starts from the reacted polymer: removing a unit type
+/// never stops a pair that already reacted from reacting, so this gives the
+/// same lengths as starting from the input. The experiments run on up to
+/// [`parallel::jobs`] threads.
+pub fn removal_report(input: &str) -> RemovalReport {
+    let result = part1_result(input);
+    let units: Vec<u8> = (b'a'..=b'z').collect();
+    let lengths = parallel::map(&units, |&unit| {
+        let clean = remove_impurities(result.clone(), unit);
+        react(&clean).len()
+    });
+    RemovalReport {
+        removals: units
+            .into_iter()
+            .zip(lengths)
+            .map(|(unit, length)| Removal {
+                unit: unit as char,
+                length,
+            })
+            .collect(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Removal {
+    pub unit: char,
+    pub length: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemovalReport {
+    pub removals: Vec<Removal>,
+}
+
+impl RemovalReport {
+    pub fn shortest(&self) -> usize {
+        self.removals.iter().map(|r| r.length).min().unwrap_or(0)
+    }
+
+    /// The unit types whose removal leaves the shortest polymer.
+    pub fn optimal(&self) -> Vec<char> {
+        let shortest = self.shortest();
+        self.removals
+            .iter()
+            .filter(|r| r.length == shortest)
+            .map(|r| r.unit)
+            .collect()
+    }
+}
+
+/// One row per unit type, with the optimal ones marked by `*`.
+impl fmt::Display for RemovalReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let shortest = self.shortest();
+        writeln!(f, "unit | length")?;
+        for Removal { unit, length } in &self.removals {
+            let mark = if *length == shortest { " *" } else { "" };
+            writeln!(f, "{unit}/{} | {length}{mark}", unit.to_ascii_uppercase())?;
+        }
+        Ok(())
+    }
+}
+
+fn remove_impurities(clean: Vec<u8>, m: u8) -> Vec<u8> {
+    clean
+        .into_iter()
+        .filter(|c| {
+            let c = if c > &96u8 { c } else { &(*c + 32u8) };
+            c != &m
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Read;
+    #[test]
+    fn annihilate_test1() {
+        assert!(annihilate(b'A', b'a'));
+    }
+    #[test]
+    fn annihilate_test2() {
+        assert!(!annihilate(b'A', b'A'));
+    }
+    #[test]
+    fn annihilate_test3() {
+        assert!(!annihilate(b'A', b'b'));
+    }
+    #[test]
+    fn part1_test1() {
+        let input = "abcCBA";
+        let result = part1_result(input);
+        println!("result={result:?}");
+        assert_eq!(result.len(), 0);
+    }
+    #[test]
+    fn part1_test2() {
+        let input = "dabcCBA";
+        let result = part1_result(input);
+        println!("result={result:?}");
+        assert_eq!(result.len(), 1);
+    }
+    #[test]
+    fn part1_test3() {
+        let input = "dabAcCaCBAcCcaDA";
+        let result = part1_result(input);
+        assert_eq!(&result, "dabCBAcaDA".as_bytes());
+    }
+    #[test]
+    fn part1_test4() {
+        let input = "abcdeEDCfghIiHGkl";
+        let result = part1_result(input);
+        assert_eq!(&result, "abfkl".as_bytes());
+    }
+
+    #[test]
+    fn react_across_read_chunks() -> io::Result<()> {
+        let input = "dabAcCaCBAcCcaDA\n";
+        let reactor = react_reader(io::BufReader::with_capacity(3, input.as_bytes()))?;
+        assert_eq!(reactor.len(), 10);
+        let long = io::repeat(b'a')
+            .take(1 << 20)
+            .chain(io::repeat(b'A').take(1 << 20));
+        assert!(react_reader(io::BufReader::new(long))?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn removal_report_example() {
+        let report = removal_report("dabAcCaCBAcCcaDA");
+        let lengths: Vec<(char, usize)> = report.removals[..4]
+            .iter()
+            .map(|r| (r.unit, r.length))
+            .collect();
+        assert_eq!(lengths, [('a', 6), ('b', 8), ('c', 4), ('d', 6)]);
+        assert_eq!(report.removals[4].length, 10);
+        assert_eq!(report.optimal(), ['c']);
+        assert!(report.to_string().contains("c/C | 4 *\n"));
+    }
+}
@@ -0,0 +1,306 @@
+use aoc_core::grid::{BBox, Grid, Point};
+use aoc_core::{Answer, Result, Solution, params, parse};
+
+/// The puzzle's limit on the summed distance for part 2.
+pub const THRESHOLD: i64 = 10_000;
+
+pub struct Day6 {
+    /// Part 2 counts the cells whose summed distance is below this, unless
+    /// the run overrides the `threshold` parameter.
+    pub threshold: i64,
+}
+
+impl Day6 {
+    pub const PUZZLE: Day6 = Day6 {
+        threshold: THRESHOLD,
+    };
+    /// The worked example uses a much smaller threshold.
+    pub const EXAMPLE: Day6 = Day6 { threshold: 32 };
+}
+
+impl Default for Day6 {
+    fn default() -> Self {
+        Day6::PUZZLE
+    }
+}
+
+impl Solution for Day6 {
+    type Parsed = Vec<Point>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse::lines(input)
+    }
+
+    fn part1(&self, points: &Self::Parsed) -> Result<Answer> {
+        part1(points)
+    }
+
+    fn part2(&self, points: &Self::Parsed) -> Result<Answer> {
+        let threshold = params::get("threshold").unwrap_or(self.threshold);
+        Ok(safe_region_size(points, threshold)?.into())
+    }
+}
+
+fn part1(points: &[Point]) -> Result<Answer> {
+    let territory = Territory::new(points)?;
+    let area = territory
+        .largest_finite_area()
+        .ok_or("every region is infinite")?;
+    Ok(area.into())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Assigned(Assigned),
+    /// Two or more coordinates are equally near.
+    Tied {
+        distance: usize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Assigned {
+    /// Index of the nearest coordinate in the input.
+    reference: usize,
+    distance: usize,
+}
+
+impl Status {
+    fn distance(&self) -> usize {
+        match *self {
+            Status::Assigned(Assigned { distance, .. }) | Status::Tied { distance } => distance,
+        }
+    }
+}
+
+/// Every cell of the coordinates' bounding box marked with its nearest coordinate.
+///
+/// A region is infinite exactly when it reaches the border of the bounding box:
+/// moving outward from a border cell keeps the same nearest coordinate forever.
+struct Territory {
+    coordinates: usize,
+    grid: Grid<Option<Status>, i32>,
+}
+
+impl Territory {
+    /// Grows all coordinates outward one step at a time. A cell reached by two
+    /// regions in the same step, or from a tied cell, is tied.
+    fn new(points: &[Point]) -> Result<Self> {
+        let bbox = BBox::from_points(points.iter().copied()).ok_or("no coordinates")?;
+        let mut grid = Grid::new(bbox, None);
+        let mut frontier = vec![];
+        for (reference, &point) in points.iter().enumerate() {
+            let status = Status::Assigned(Assigned {
+                reference,
+                distance: 0,
+            });
+            if claim(&mut grid[point], status) {
+                frontier.push(point);
+            }
+        }
+        while !frontier.is_empty() {
+            let mut next = vec![];
+            for point in frontier {
+                let status = match grid[point].expect("frontier cells are claimed") {
+                    Status::Assigned(a) => Status::Assigned(Assigned {
+                        distance: a.distance + 1,
+                        ..a
+                    }),
+                    Status::Tied { distance } => Status::Tied {
+                        distance: distance + 1,
+                    },
+                };
+                for neighbour in point.neighbours4() {
+                    if let Some(cell) = grid.get_mut(neighbour)
+                        && claim(cell, status)
+                    {
+                        next.push(neighbour);
+                    }
+                }
+            }
+            frontier = next;
+        }
+        Ok(Territory {
+            coordinates: points.len(),
+            grid,
+        })
+    }
+
+    /// The size of the largest region that does not reach the border. A
+    /// coordinate that owns no cells, such as a repeated one, has no region.
+    fn largest_finite_area(&self) -> Option<usize> {
+        let bbox = self.grid.bbox()?;
+        let mut areas = vec![0; self.coordinates];
+        let mut infinite = vec![false; self.coordinates];
+        for (point, status) in self.grid.iter() {
+            if let Some(Status::Assigned(Assigned { reference, .. })) = *status {
+                areas[reference] += 1;
+                infinite[reference] |= bbox.on_border(point);
+            }
+        }
+        (0..self.coordinates)
+            .filter(|&r| areas[r] > 0 && !infinite[r])
+            .map(|r| areas[r])
+            .max()
+    }
+}
+
+/// Marks `cell` with `status` if it is unclaimed, or as tied if another region
+/// reached it at the same distance. Returns whether the cell was newly claimed.
+fn claim(cell: &mut Option<Status>, status: Status) -> bool {
+    match cell {
+        None => {
+            *cell = Some(status);
+            true
+        }
+        Some(existing) if existing.distance() == status.distance() && *existing != status => {
+            *existing = Status::Tied {
+                distance: status.distance(),
+            };
+            false
+        }
+        Some(_) => false,
+    }
+}
+
+/// Counts the cells whose summed Manhattan distance to all `points` is below
+/// `threshold`.
+///
+/// The sum splits into an x part and a y part, so each axis is tabulated on
+/// its own and the cells are counted by pairing the two tables. The region can
+/// extend past the bounding box, but by less than `threshold / points` cells,
+/// since every step outside it adds one to the distance of each point.
+pub fn safe_region_size(points: &[Point], threshold: i64) -> Result<usize> {
+    if points.is_empty() {
+        return Err("no coordinates".into());
+    }
+    let margin = threshold / points.len() as i64 + 1;
+    let xs: Vec<i64> = points.iter().map(|p| p.x as i64).collect();
+    let ys: Vec<i64> = points.iter().map(|p| p.y as i64).collect();
+    let below = |total: &i64| *total < threshold;
+    let xs: Vec<i64> = axis_distances(&xs, margin)
+        .into_iter()
+        .filter(below)
+        .collect();
+    let mut ys: Vec<i64> = axis_distances(&ys, margin)
+        .into_iter()
+        .filter(below)
+        .collect();
+    ys.sort_unstable();
+    Ok(xs
+        .iter()
+        .map(|x| ys.partition_point(|y| x + y < threshold))
+        .sum())
+}
+
+/// The summed distance from each position to `values`, for every position from
+/// `margin` before the smallest value to `margin` after the largest.
+fn axis_distances(values: &[i64], margin: i64) -> Vec<i64> {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    let mut prefix = vec![0];
+    for v in &sorted {
+        prefix.push(prefix.last().unwrap() + v);
+    }
+    let (n, total) = (sorted.len() as i64, prefix[sorted.len()]);
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+    (min - margin..=max + margin)
+        .map(|position| {
+            let below = sorted.partition_point(|v| *v < position);
+            let (k, left) = (below as i64, prefix[below]);
+            (position * k - left) + (total - left - position * (n - k))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/example1.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let points = Day6::EXAMPLE.parse(EXAMPLE)?;
+        assert_eq!(Day6::EXAMPLE.part1(&points)?, Answer::Int(17));
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let points = Day6::EXAMPLE.parse(EXAMPLE)?;
+        assert_eq!(Day6::EXAMPLE.part2(&points)?, Answer::Int(16));
+        assert_eq!(Day6::default().threshold, THRESHOLD);
+        Ok(())
+    }
+
+    fn brute_force_region(points: &[Point], threshold: i64) -> usize {
+        let reach = threshold as i32;
+        let bbox = BBox::new(Point::new(-reach, -reach), Point::new(reach, reach));
+        bbox.points()
+            .filter(|&cell| {
+                let total: i32 = points.iter().map(|&p| p.manhattan(cell)).sum();
+                (total as i64) < threshold
+            })
+            .count()
+    }
+
+    #[test]
+    fn region_extends_past_bounding_box() -> Result<()> {
+        let single = [Point::new(0, 0)];
+        assert_eq!(safe_region_size(&single, 3)?, 13);
+        let points = Day6::default().parse(EXAMPLE)?;
+        for threshold in [0, 1, 20, 32, 60, 100] {
+            assert_eq!(
+                safe_region_size(&points, threshold)?,
+                brute_force_region(&points, threshold),
+                "threshold {threshold}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn repeated_coordinate_has_no_region() -> Result<()> {
+        let points = Day6::default().parse("1, 1\n1, 1")?;
+        assert!(Day6::default().part1(&points).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn equidistant_cells_are_tied() -> Result<()> {
+        let points = [Point::new(0, 0), Point::new(2, 0), Point::new(1, 2)];
+        let territory = Territory::new(&points)?;
+        assert_eq!(
+            territory.grid[Point::new(1, 0)],
+            Some(Status::Tied { distance: 1 })
+        );
+        // (1, 1) is one step from the third point and two from the others.
+        assert_eq!(
+            territory.grid[Point::new(1, 1)],
+            Some(Status::Assigned(Assigned {
+                reference: 2,
+                distance: 1
+            }))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn regions_on_the_border_are_infinite() -> Result<()> {
+        let points = [Point::new(0, 0), Point::new(4, 0), Point::new(2, 2)];
+        let territory = Territory::new(&points)?;
+        assert_eq!(territory.largest_finite_area(), None);
+        let points = [
+            Point::new(0, 0),
+            Point::new(6, 0),
+            Point::new(0, 6),
+            Point::new(6, 6),
+            Point::new(3, 3),
+        ];
+        let territory = Territory::new(&points)?;
+        // The centre owns the cells within distance 2 of it.
+        assert_eq!(territory.largest_finite_area(), Some(13));
+        Ok(())
+    }
+}
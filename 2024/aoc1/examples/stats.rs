@@ -0,0 +1,32 @@
+//! Prints summary statistics for the two location lists.
+//!
+//! ```sh
+//! cargo run -p aoc2024-day1 --example stats -- [lists]
+//! ```
+//!
+//! Without a path the day's puzzle input is used.
+
+use aoc_core::{Result, Solution};
+use aoc2024_day1::{Day1, ListStats};
+use std::path::Path;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<()> {
+    let input = match std::env::args().nth(1) {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => aoc_core::input::read_input(Path::new(env!("CARGO_MANIFEST_DIR")))?,
+    };
+    let (left, right) = Day1.parse(&input)?;
+    print!("{}", ListStats::new(&left, &right));
+    Ok(())
+}
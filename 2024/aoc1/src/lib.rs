@@ -0,0 +1,143 @@
+use aoc_core::{Answer, Result, Solution, parse};
+use std::collections::HashMap;
+use std::fmt;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = (Vec<i32>, Vec<i32>);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        let [left, right] = parse::columns(input)?;
+        Ok((left, right))
+    }
+
+    fn part1(&self, (left, right): &Self::Parsed) -> Result<Answer> {
+        Ok(gaps(left, right).iter().sum::<i64>().into())
+    }
+
+    fn part2(&self, (left, right): &Self::Parsed) -> Result<Answer> {
+        Ok(similarity(left, right).into())
+    }
+}
+
+/// The distance between the smallest numbers of the two lists, then the next
+/// smallest, and so on.
+pub fn gaps(left: &[i32], right: &[i32]) -> Vec<i64> {
+    let (mut left, mut right) = (left.to_vec(), right.to_vec());
+    left.sort_unstable();
+    right.sort_unstable();
+    left.iter()
+        .zip(&right)
+        .map(|(&l, &r)| (i64::from(l) - i64::from(r)).abs())
+        .collect()
+}
+
+/// Each number in `left` times the number of times it appears in `right`.
+pub fn similarity(left: &[i32], right: &[i32]) -> i64 {
+    let counts = occurrences(right);
+    left.iter()
+        .map(|n| i64::from(*n) * counts.get(n).copied().unwrap_or(0) as i64)
+        .sum()
+}
+
+fn occurrences(list: &[i32]) -> HashMap<i32, usize> {
+    let mut counts = HashMap::new();
+    for &n in list {
+        *counts.entry(n).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Summary numbers for a pair of location lists.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListStats {
+    pub pairs: usize,
+    pub total_gap: i64,
+    /// The middle gap, or the mean of the two middle gaps for an even count.
+    pub median_gap: Option<f64>,
+    pub largest_gap: Option<i64>,
+    /// The number appearing most often across both lists, the smallest on a
+    /// tie, with its count.
+    pub most_frequent: Option<(i32, usize)>,
+    pub similarity: i64,
+}
+
+impl ListStats {
+    pub fn new(left: &[i32], right: &[i32]) -> Self {
+        let mut gaps = gaps(left, right);
+        gaps.sort_unstable();
+        let median_gap = match gaps.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(gaps[n / 2] as f64),
+            n => Some((gaps[n / 2 - 1] + gaps[n / 2]) as f64 / 2.0),
+        };
+        let mut counts = occurrences(left);
+        for (n, count) in occurrences(right) {
+            *counts.entry(n).or_insert(0) += count;
+        }
+        let most_frequent = counts
+            .into_iter()
+            .max_by_key(|&(n, count)| (count, std::cmp::Reverse(n)));
+        ListStats {
+            pairs: gaps.len(),
+            total_gap: gaps.iter().sum(),
+            median_gap,
+            largest_gap: gaps.last().copied(),
+            most_frequent,
+            similarity: similarity(left, right),
+        }
+    }
+}
+
+impl fmt::Display for ListStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "pairs:         {}", self.pairs)?;
+        writeln!(f, "total gap:     {}", self.total_gap)?;
+        match self.median_gap {
+            Some(median) => writeln!(f, "median gap:    {median}")?,
+            None => writeln!(f, "median gap:    -")?,
+        }
+        match self.largest_gap {
+            Some(largest) => writeln!(f, "largest gap:   {largest}")?,
+            None => writeln!(f, "largest gap:   -")?,
+        }
+        match self.most_frequent {
+            Some((n, count)) => writeln!(f, "most frequent: {n} ({count} times)")?,
+            None => writeln!(f, "most frequent: -")?,
+        }
+        writeln!(f, "similarity:    {}", self.similarity)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> (Vec<i32>, Vec<i32>) {
+        Day1.parse(include_str!("../input/example1.txt"))
+            .expect("example parses")
+    }
+
+    #[test]
+    fn part1_and_part2_example() -> Result<()> {
+        let lists = example();
+        assert_eq!(Day1.part1(&lists)?, Answer::Int(11));
+        assert_eq!(Day1.part2(&lists)?, Answer::Int(31));
+        Ok(())
+    }
+
+    #[test]
+    fn stats_example() {
+        let (left, right) = example();
+        let stats = ListStats::new(&left, &right);
+        assert_eq!(stats.pairs, 6);
+        assert_eq!(stats.total_gap, 11);
+        assert_eq!(stats.median_gap, Some(1.5));
+        assert_eq!(stats.largest_gap, Some(5));
+        assert_eq!(stats.most_frequent, Some((3, 6)));
+        assert_eq!(stats.similarity, 31);
+        assert!(stats.to_string().contains("most frequent: 3 (6 times)"));
+        assert_eq!(ListStats::new(&[], &[]).median_gap, None);
+    }
+}
@@ -0,0 +1,151 @@
+use std::time::{Duration, Instant};
+
+use crate::Result;
+use crate::runner::{DynSolution, Part};
+
+/// How many times to run each phase.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchOptions {
+    /// Untimed runs before measuring, to warm caches and the allocator.
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            warmup: 3,
+            runs: 10,
+        }
+    }
+}
+
+/// Summary of repeated timings of one phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub median: Duration,
+    /// Median absolute deviation from the median.
+    pub mad: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Panics if `samples` is empty.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        samples.sort();
+        let median = median(&samples);
+        let mut deviations: Vec<Duration> = samples.iter().map(|s| s.abs_diff(median)).collect();
+        deviations.sort();
+        Stats {
+            runs: samples.len(),
+            median,
+            mad: self::median(&deviations),
+            min: samples[0],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+fn median(sorted: &[Duration]) -> Duration {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Runs `f` `warmup` times untimed, then `runs` times timed.
+pub fn measure<F>(options: BenchOptions, mut f: F) -> Result<Stats>
+where
+    F: FnMut() -> Result<()>,
+{
+    if options.runs == 0 {
+        return Err("need at least one timed run".into());
+    }
+    for _ in 0..options.warmup {
+        f()?;
+    }
+    let mut samples = Vec::with_capacity(options.runs);
+    for _ in 0..options.runs {
+        let start = Instant::now();
+        f()?;
+        samples.push(start.elapsed());
+    }
+    Ok(Stats::from_samples(samples))
+}
+
+#[derive(Debug)]
+pub struct BenchReport {
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+}
+
+/// Times parsing and each of `parts` separately. Parts reuse one parsed input,
+/// so their timings exclude parsing.
+pub fn bench(
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[Part],
+    options: BenchOptions,
+) -> Result<BenchReport> {
+    let parse = measure(options, || solution.parse_any(input).map(drop))?;
+    let parsed = solution.parse_any(input)?;
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let stats = measure(options, || {
+                solution.solve_any(part, parsed.as_ref()).map(drop)
+            })?;
+            Ok((part, stats))
+        })
+        .collect::<Result<_>>()?;
+    Ok(BenchReport { parse, parts })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn stats_of_odd_sample() {
+        let stats = Stats::from_samples(vec![ms(5), ms(1), ms(3), ms(100), ms(4)]);
+        assert_eq!(stats.median, ms(4));
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.max, ms(100));
+        // deviations: 1, 3, 1, 96, 0 -> median 1
+        assert_eq!(stats.mad, ms(1));
+    }
+
+    #[test]
+    fn stats_of_even_sample() {
+        let stats = Stats::from_samples(vec![ms(2), ms(4), ms(6), ms(8)]);
+        assert_eq!(stats.median, ms(5));
+        assert_eq!(stats.mad, ms(2));
+    }
+
+    #[test]
+    fn measure_counts_runs() -> Result<()> {
+        let mut calls = 0;
+        let stats = measure(BenchOptions { warmup: 2, runs: 5 }, || {
+            calls += 1;
+            Ok(())
+        })?;
+        assert_eq!(calls, 7);
+        assert_eq!(stats.runs, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn measure_propagates_errors() {
+        let result = measure(BenchOptions::default(), || Err("boom".into()));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,205 @@
+use std::fmt;
+
+/// Error type shared by all days.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("{0}")]
+    Message(String),
+    #[error(transparent)]
+    Other(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl Error {
+    /// Wraps an error from another library.
+    pub fn other<E>(error: E) -> Self
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        Error::Other(Box::new(error))
+    }
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::Message(message)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Error::Message(message.to_string())
+    }
+}
+
+impl From<std::num::ParseIntError> for Error {
+    fn from(error: std::num::ParseIntError) -> Self {
+        Error::other(error)
+    }
+}
+
+impl From<Box<dyn std::error::Error + Send + Sync>> for Error {
+    fn from(error: Box<dyn std::error::Error + Send + Sync>) -> Self {
+        Error::Other(error)
+    }
+}
+
+/// A problem with part of a single line, located by byte offsets into that line.
+///
+/// Parsers that only see one line return this; [`crate::parse::lines_with`]
+/// turns it into a [`ParseError`] once the line number is known. There is
+/// deliberately no conversion straight into [`Error`], which would drop the
+/// span: go through [`ParseError::new`] instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineError {
+    pub start: usize,
+    pub end: usize,
+    pub message: String,
+}
+
+impl LineError {
+    /// A problem with the line as a whole.
+    pub fn new(message: impl Into<String>) -> Self {
+        LineError {
+            start: 0,
+            end: usize::MAX,
+            message: message.into(),
+        }
+    }
+
+    /// A problem with the bytes `start..end` of the line.
+    pub fn at(range: std::ops::Range<usize>, message: impl Into<String>) -> Self {
+        LineError {
+            start: range.start,
+            end: range.end,
+            message: message.into(),
+        }
+    }
+
+    /// A problem with `token`, which must be a subslice of `line`.
+    pub fn token(line: &str, token: &str, message: impl Into<String>) -> Self {
+        let start = (token.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|start| start + token.len() <= line.len())
+            .expect("token is a subslice of line");
+        LineError::at(start..start + token.len(), message)
+    }
+
+    /// Moves the span right by `offset` bytes.
+    pub fn shifted(mut self, offset: usize) -> Self {
+        self.start = self.start.saturating_add(offset);
+        self.end = self.end.saturating_add(offset);
+        self
+    }
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for LineError {}
+
+/// A malformed line of puzzle input, displayed as a caret diagnostic:
+///
+/// ```text
+/// line 3, column 3: invalid digit found in string
+///   |
+/// 3 | +1x
+///   |   ^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// Number of characters the problem spans, at least 1.
+    pub width: usize,
+    /// The whole offending line.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Locates `error` in `text`, the `line`th line (1-based) of the input.
+    pub fn new(line: usize, text: &str, error: LineError) -> Self {
+        let start = floor_char_boundary(text, error.start.min(text.len()));
+        let end = floor_char_boundary(text, error.end.min(text.len())).max(start);
+        ParseError {
+            line,
+            column: text[..start].chars().count() + 1,
+            width: text[start..end].chars().count().max(1),
+            text: text.to_string(),
+            message: error.message,
+        }
+    }
+}
+
+fn floor_char_boundary(text: &str, mut index: usize) -> usize {
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{number} | {}", self.text)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width)
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn caret_points_at_span() {
+        let text = "#1 @ 1,x: 4x4";
+        let error = ParseError::new(12, text, LineError::at(7..8, "expected a number"));
+        assert_eq!(error.column, 8);
+        assert_eq!(
+            error.to_string(),
+            "line 12, column 8: expected a number\n   |\n12 | #1 @ 1,x: 4x4\n   |        ^"
+        );
+    }
+
+    #[test]
+    fn whole_line_error_underlines_everything() {
+        let error = ParseError::new(1, "abc", LineError::new("bad"));
+        assert_eq!((error.column, error.width), (1, 3));
+    }
+
+    #[test]
+    fn token_offsets_follow_subslice() {
+        let line = "3   x";
+        let token = line.split_whitespace().nth(1).unwrap();
+        assert_eq!(LineError::token(line, token, "bad").start, 4);
+    }
+
+    #[test]
+    fn columns_count_characters() {
+        let error = ParseError::new(1, "é x", LineError::at(3..4, "bad"));
+        assert_eq!(error.column, 3);
+    }
+}
@@ -0,0 +1,297 @@
+//! Lazy iteration over the lattice points of Manhattan and Chebyshev shapes in
+//! any number of dimensions.
+//!
+//! Points are arrays `[T; N]`; use `Point::from` / `<[T; 2]>::from` to move
+//! between these and [`crate::grid::Point`]. Offsets are computed in `i64`, and
+//! points that do not fit in `T` are skipped.
+
+use crate::grid::Coord;
+
+pub fn manhattan<T: Coord, const N: usize>(a: [T; N], b: [T; N]) -> u64 {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| a.to_i64().abs_diff(b.to_i64()))
+        .sum()
+}
+
+pub fn chebyshev<T: Coord, const N: usize>(a: [T; N], b: [T; N]) -> u64 {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| a.to_i64().abs_diff(b.to_i64()))
+        .max()
+        .unwrap_or(0)
+}
+
+/// The points at exactly Manhattan distance `radius` from `center`.
+pub fn manhattan_ring<T: Coord, const N: usize>(
+    center: [T; N],
+    radius: u64,
+) -> impl Iterator<Item = [T; N]> {
+    ManhattanRing::new(radius).filter_map(move |offset| translate(center, offset))
+}
+
+/// The points within Manhattan distance `radius` of `center`, nearest rings first.
+pub fn manhattan_ball<T: Coord, const N: usize>(
+    center: [T; N],
+    radius: u64,
+) -> impl Iterator<Item = [T; N]> {
+    (0..=radius).flat_map(move |r| manhattan_ring(center, r))
+}
+
+/// The points at exactly Chebyshev distance `radius` from `center`: the
+/// surface of the cube with side `2 * radius + 1`.
+pub fn chebyshev_ring<T: Coord, const N: usize>(
+    center: [T; N],
+    radius: u64,
+) -> impl Iterator<Item = [T; N]> {
+    ChebyshevRing::new(radius).filter_map(move |offset| translate(center, offset))
+}
+
+/// The points that lie within every diamond, each given as `(center, radius)`.
+///
+/// Walks the smallest diamond and keeps the points inside all the others, so
+/// the cost is bounded by the size of the smallest one. An empty list yields
+/// nothing rather than the whole (unbounded) space.
+pub fn diamond_intersection<T: Coord, const N: usize>(
+    diamonds: &[([T; N], u64)],
+) -> impl Iterator<Item = [T; N]> + use<'_, T, N> {
+    let smallest = diamonds.iter().min_by_key(|(_, radius)| *radius).copied();
+    smallest
+        .into_iter()
+        .flat_map(|(center, radius)| manhattan_ball(center, radius))
+        .filter(|&p| {
+            diamonds
+                .iter()
+                .all(|&(center, radius)| manhattan(p, center) <= radius)
+        })
+}
+
+fn translate<T: Coord, const N: usize>(center: [T; N], offset: [i64; N]) -> Option<[T; N]> {
+    let mut point = center;
+    for (c, d) in point.iter_mut().zip(offset) {
+        *c = T::from_i64(c.to_i64() + d)?;
+    }
+    Some(point)
+}
+
+/// Offsets with `|o_0| + ... + |o_{N-1}| == radius`.
+///
+/// The first `N - 1` coordinates are an odometer, each ranging over what is
+/// left of the radius after the ones before it; the last coordinate takes the
+/// remainder with either sign.
+struct ManhattanRing<const N: usize> {
+    radius: i64,
+    offset: [i64; N],
+    negative: bool,
+    done: bool,
+}
+
+impl<const N: usize> ManhattanRing<N> {
+    fn new(radius: u64) -> Self {
+        assert!(N > 0, "points need at least one dimension");
+        let mut ring = ManhattanRing {
+            radius: radius as i64,
+            offset: [0; N],
+            negative: false,
+            done: false,
+        };
+        ring.reset_from(0);
+        ring
+    }
+
+    /// What is left of the radius for coordinate `k`.
+    fn remaining(&self, k: usize) -> i64 {
+        self.radius - self.offset[..k].iter().map(|o| o.abs()).sum::<i64>()
+    }
+
+    /// Sets coordinates `k..N - 1` to the start of their ranges.
+    fn reset_from(&mut self, k: usize) {
+        for j in k..N - 1 {
+            self.offset[j] = -self.remaining(j);
+        }
+    }
+}
+
+impl<const N: usize> Iterator for ManhattanRing<N> {
+    type Item = [i64; N];
+
+    fn next(&mut self) -> Option<[i64; N]> {
+        if self.done {
+            return None;
+        }
+        let last = self.remaining(N - 1);
+        let mut point = self.offset;
+        point[N - 1] = if self.negative { -last } else { last };
+
+        if !self.negative && last > 0 {
+            self.negative = true;
+            return Some(point);
+        }
+        self.negative = false;
+        match (0..N - 1)
+            .rev()
+            .find(|&k| self.offset[k] < self.remaining(k))
+        {
+            Some(k) => {
+                self.offset[k] += 1;
+                self.reset_from(k + 1);
+            }
+            None => self.done = true,
+        }
+        Some(point)
+    }
+}
+
+/// Offsets with `max(|o_i|) == radius`.
+///
+/// An odometer over the whole cube, except that the last coordinate jumps from
+/// `-radius` straight to `radius` while every other coordinate is inside.
+struct ChebyshevRing<const N: usize> {
+    radius: i64,
+    offset: [i64; N],
+    done: bool,
+}
+
+impl<const N: usize> ChebyshevRing<N> {
+    fn new(radius: u64) -> Self {
+        assert!(N > 0, "points need at least one dimension");
+        let radius = radius as i64;
+        ChebyshevRing {
+            radius,
+            offset: [-radius; N],
+            done: false,
+        }
+    }
+
+    fn on_surface(&self) -> bool {
+        self.offset[..N - 1].iter().any(|o| o.abs() == self.radius)
+    }
+}
+
+impl<const N: usize> Iterator for ChebyshevRing<N> {
+    type Item = [i64; N];
+
+    fn next(&mut self) -> Option<[i64; N]> {
+        if self.done {
+            return None;
+        }
+        let point = self.offset;
+
+        let last = self.offset[N - 1];
+        if last < self.radius {
+            self.offset[N - 1] = if self.on_surface() {
+                last + 1
+            } else {
+                self.radius
+            };
+            return Some(point);
+        }
+        match (0..N - 1).rev().find(|&k| self.offset[k] < self.radius) {
+            Some(k) => {
+                self.offset[k] += 1;
+                self.offset[k + 1..].fill(-self.radius);
+            }
+            None => self.done = true,
+        }
+        Some(point)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::grid::Point;
+    use std::collections::HashSet;
+
+    /// Every point of the cube of side `2 * reach + 1` around `center`.
+    fn cube<const N: usize>(center: [i32; N], reach: i32) -> Vec<[i32; N]> {
+        let mut points = vec![center];
+        for axis in 0..N {
+            points = points
+                .into_iter()
+                .flat_map(|p| {
+                    (-reach..=reach).map(move |d| {
+                        let mut q = p;
+                        q[axis] += d;
+                        q
+                    })
+                })
+                .collect();
+        }
+        points
+    }
+
+    /// Collects `points`, checking that none repeat.
+    fn distinct<const N: usize>(points: impl Iterator<Item = [i32; N]>) -> HashSet<[i32; N]> {
+        let mut seen = HashSet::new();
+        for p in points {
+            assert!(seen.insert(p), "{p:?} produced twice");
+        }
+        seen
+    }
+
+    fn check_rings<const N: usize>(center: [i32; N]) {
+        for radius in 0..=5 {
+            let around = cube(center, radius as i32 + 1);
+            let expected = |d: fn([i32; N], [i32; N]) -> u64, within: bool| -> HashSet<_> {
+                around
+                    .iter()
+                    .copied()
+                    .filter(|&p| match within {
+                        true => d(p, center) <= radius,
+                        false => d(p, center) == radius,
+                    })
+                    .collect()
+            };
+            assert_eq!(
+                distinct(manhattan_ring(center, radius)),
+                expected(manhattan, false),
+                "manhattan ring {radius} in {N}d"
+            );
+            assert_eq!(
+                distinct(manhattan_ball(center, radius)),
+                expected(manhattan, true),
+                "manhattan ball {radius} in {N}d"
+            );
+            assert_eq!(
+                distinct(chebyshev_ring(center, radius)),
+                expected(chebyshev, false),
+                "chebyshev ring {radius} in {N}d"
+            );
+        }
+    }
+
+    #[test]
+    fn rings_match_brute_force() {
+        check_rings([3]);
+        check_rings([1, -2]);
+        check_rings([0, 4, -1]);
+        check_rings([2, 0, -3, 1]);
+    }
+
+    fn check_intersection<const N: usize>(diamonds: &[([i32; N], u64)]) {
+        let expected: HashSet<_> = cube([0; N], 6)
+            .into_iter()
+            .filter(|&p| diamonds.iter().all(|&(c, r)| manhattan(p, c) <= r))
+            .collect();
+        assert_eq!(distinct(diamond_intersection(diamonds)), expected);
+    }
+
+    #[test]
+    fn diamond_intersection_matches_brute_force() {
+        assert_eq!(diamond_intersection::<i32, 2>(&[]).count(), 0);
+        check_intersection(&[([0, 0], 3), ([2, 1], 2)]);
+        check_intersection(&[([0, 0], 1), ([3, 0], 1)]);
+        check_intersection(&[([-1, 2], 4), ([1, -1], 3), ([0, 0], 5)]);
+        check_intersection(&[([0, 0, 0], 3), ([1, 1, 1], 2), ([-1, 0, 2], 3)]);
+        check_intersection(&[([0, 0, 0, 0], 3), ([2, -1, 0, 1], 2)]);
+    }
+
+    #[test]
+    fn unsigned_points_are_clipped() {
+        let ring: Vec<[u32; 2]> = manhattan_ring([0, 0], 1).collect();
+        assert_eq!(ring.len(), 2);
+        let points: Vec<Point<u32>> = chebyshev_ring([0, 0], 1).map(Point::from).collect();
+        assert_eq!(points.len(), 3);
+    }
+}
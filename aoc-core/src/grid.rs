@@ -0,0 +1,586 @@
+//! Points, bounding boxes and grids for the 2D puzzles.
+//!
+//! Coordinates are generic over [`Coord`] so a day can keep whatever integer
+//! type its input uses. `y` grows downward, matching how puzzle inputs are read.
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+use std::ops::{Add, Index, IndexMut, Sub};
+use std::str::FromStr;
+
+use crate::Result;
+use crate::error::{LineError, ParseError};
+
+/// Integer types that can be used as grid coordinates.
+pub trait Coord:
+    Copy + Ord + Hash + Debug + Display + Add<Output = Self> + Sub<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn to_i64(self) -> i64;
+
+    /// `None` when `value` does not fit in the type.
+    fn from_i64(value: i64) -> Option<Self>;
+}
+
+macro_rules! coord {
+    ($($t:ty),*) => {$(
+        impl Coord for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn to_i64(self) -> i64 {
+                self as i64
+            }
+
+            fn from_i64(value: i64) -> Option<Self> {
+                Self::try_from(value).ok()
+            }
+        }
+    )*};
+}
+
+coord!(i32, i64, u32, usize);
+
+/// Offsets of the 4-connected neighbours: up, left, right, down.
+pub const ORTHOGONAL: [(i64, i64); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of the 8-connected neighbours in reading order.
+pub const ADJACENT: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coord> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+
+    /// The point moved by `(dx, dy)`, or `None` if it leaves the coordinate type.
+    pub fn offset(self, dx: i64, dy: i64) -> Option<Self> {
+        Some(Point {
+            x: T::from_i64(self.x.to_i64() + dx)?,
+            y: T::from_i64(self.y.to_i64() + dy)?,
+        })
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+
+    /// The 4-connected neighbours that are representable in `T`.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.offset(dx, dy))
+    }
+
+    /// The 8-connected neighbours that are representable in `T`.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        ADJACENT
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.offset(dx, dy))
+    }
+}
+
+fn abs_diff<T: Coord>(a: T, b: T) -> T {
+    if a > b { a - b } else { b - a }
+}
+
+impl<T: Coord> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coord> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T> From<[T; 2]> for Point<T> {
+    fn from([x, y]: [T; 2]) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T> From<Point<T>> for [T; 2] {
+    fn from(p: Point<T>) -> Self {
+        [p.x, p.y]
+    }
+}
+
+impl<T: Coord> Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Parses `x,y`, with optional whitespace around either number.
+impl<T> FromStr for Point<T>
+where
+    T: Coord + FromStr,
+    T::Err: Display,
+{
+    type Err = LineError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| LineError::new("expected a point like `1, 6`"))?;
+        let coordinate = |c: &str| {
+            let c = c.trim();
+            c.parse::<T>()
+                .map_err(|e| LineError::token(s, c, e.to_string()))
+        };
+        Ok(Point::new(coordinate(x)?, coordinate(y)?))
+    }
+}
+
+/// An axis-aligned box with inclusive corners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BBox<T = i32> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T: Coord> BBox<T> {
+    pub fn new(min: Point<T>, max: Point<T>) -> Self {
+        debug_assert!(min.x <= max.x && min.y <= max.y, "empty bounding box");
+        BBox { min, max }
+    }
+
+    /// The box of `width` by `height` cells with its top-left corner at `origin`.
+    /// Both sizes must be positive.
+    pub fn from_size(origin: Point<T>, width: T, height: T) -> Self {
+        BBox::new(
+            origin,
+            Point::new(origin.x + width - T::ONE, origin.y + height - T::ONE),
+        )
+    }
+
+    /// The smallest box holding every point, or `None` for no points.
+    pub fn from_points<I: IntoIterator<Item = Point<T>>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut bbox = BBox::new(first, first);
+        points.for_each(|p| bbox.include(p));
+        Some(bbox)
+    }
+
+    /// Grows the box so that it contains `p`.
+    pub fn include(&mut self, p: Point<T>) {
+        self.min = Point::new(self.min.x.min(p.x), self.min.y.min(p.y));
+        self.max = Point::new(self.max.x.max(p.x), self.max.y.max(p.y));
+    }
+
+    /// The box grown by `margin` cells on every side.
+    ///
+    /// Panics if the grown box does not fit in `T`.
+    pub fn expand(self, margin: T) -> Self {
+        let margin = margin.to_i64();
+        let grow = |p: Point<T>, d: i64| p.offset(d, d).expect("expanded box fits in coordinates");
+        BBox::new(grow(self.min, -margin), grow(self.max, margin))
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.x.to_i64() - self.min.x.to_i64() + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y.to_i64() - self.min.y.to_i64() + 1) as usize
+    }
+
+    pub fn area(&self) -> usize {
+        self.width() * self.height()
+    }
+
+    pub fn contains(&self, p: Point<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    pub fn on_border(&self, p: Point<T>) -> bool {
+        self.contains(p)
+            && (p.x == self.min.x || p.x == self.max.x || p.y == self.min.y || p.y == self.max.y)
+    }
+
+    /// The cells covered by both boxes.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = Point::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Point::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+        (min.x <= max.x && min.y <= max.y).then_some(BBox { min, max })
+    }
+
+    /// Every point in the box, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point<T>> + use<T> {
+        let BBox { min, max } = *self;
+        Steps::new(min.y, max.y)
+            .flat_map(move |y| Steps::new(min.x, max.x).map(move |x| Point::new(x, y)))
+    }
+}
+
+/// `start..=end` for any [`Coord`].
+struct Steps<T> {
+    next: Option<T>,
+    end: T,
+}
+
+impl<T: Coord> Steps<T> {
+    fn new(start: T, end: T) -> Self {
+        Steps {
+            next: (start <= end).then_some(start),
+            end,
+        }
+    }
+}
+
+impl<T: Coord> Iterator for Steps<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let current = self.next?;
+        self.next = (current < self.end).then(|| current + T::ONE);
+        Some(current)
+    }
+}
+
+/// A rectangular grid stored row-major in a `Vec`, with its top-left cell at
+/// `origin`. A grid parsed from empty input has no cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<V, T = usize> {
+    origin: Point<T>,
+    width: usize,
+    height: usize,
+    cells: Vec<V>,
+}
+
+impl<V: Clone, T: Coord> Grid<V, T> {
+    /// A grid over `bbox` with every cell set to `fill`.
+    pub fn new(bbox: BBox<T>, fill: V) -> Self {
+        Grid {
+            origin: bbox.min,
+            width: bbox.width(),
+            height: bbox.height(),
+            cells: vec![fill; bbox.area()],
+        }
+    }
+}
+
+impl<V, T: Coord> Grid<V, T> {
+    /// The area covered by the grid, or `None` when it has no cells.
+    pub fn bbox(&self) -> Option<BBox<T>> {
+        let max = self
+            .origin
+            .offset(self.width as i64 - 1, self.height as i64 - 1)?;
+        (!self.cells.is_empty()).then_some(BBox::new(self.origin, max))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point<T>) -> bool {
+        self.index_of(p).is_some()
+    }
+
+    pub fn get(&self, p: Point<T>) -> Option<&V> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point<T>) -> Option<&mut V> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<T>, &V)> {
+        self.cells.iter().enumerate().map(|(i, v)| {
+            let (dx, dy) = (i % self.width, i / self.width);
+            let p = self.origin.offset(dx as i64, dy as i64);
+            (p.expect("grid cells fit in coordinates"), v)
+        })
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[V]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The 4-connected neighbours of `p` that lie inside the grid.
+    pub fn neighbours4(&self, p: Point<T>) -> impl Iterator<Item = Point<T>> + use<'_, V, T> {
+        p.neighbours4().filter(|&n| self.contains(n))
+    }
+
+    /// The 8-connected neighbours of `p` that lie inside the grid.
+    pub fn neighbours8(&self, p: Point<T>) -> impl Iterator<Item = Point<T>> + use<'_, V, T> {
+        p.neighbours8().filter(|&n| self.contains(n))
+    }
+
+    /// Position of `p` in `cells`, if it is inside the grid.
+    fn index_of(&self, p: Point<T>) -> Option<usize> {
+        let dx = usize::try_from(p.x.to_i64() - self.origin.x.to_i64()).ok()?;
+        let dy = usize::try_from(p.y.to_i64() - self.origin.y.to_i64()).ok()?;
+        (dx < self.width && dy < self.height).then_some(dy * self.width + dx)
+    }
+}
+
+impl Grid<u8> {
+    /// Reads a block of text into a grid of bytes with `(0, 0)` at the top left.
+    /// Rows of different lengths are reported as a parse error.
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        for (index, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let expected = *width.get_or_insert(line.len());
+            if line.len() != expected {
+                let message = format!("expected a row of {expected} cells, found {}", line.len());
+                return Err(ParseError::new(index + 1, line, LineError::new(message)).into());
+            }
+            cells.extend_from_slice(line.as_bytes());
+            height += 1;
+        }
+        Ok(Grid {
+            origin: Point::new(0, 0),
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+}
+
+impl<V, T: Coord> Index<Point<T>> for Grid<V, T> {
+    type Output = V;
+
+    fn index(&self, p: Point<T>) -> &V {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{p} is outside the grid"))
+    }
+}
+
+impl<V, T: Coord> IndexMut<Point<T>> for Grid<V, T> {
+    fn index_mut(&mut self, p: Point<T>) -> &mut V {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{p} is outside the grid"))
+    }
+}
+
+/// A grid holding only the cells that have been set, for unbounded or mostly
+/// empty areas.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<V, T = i32> {
+    cells: HashMap<Point<T>, V>,
+}
+
+impl<V, T: Coord> Default for SparseGrid<V, T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<V, T: Coord> SparseGrid<V, T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, p: Point<T>) -> Option<&V> {
+        self.cells.get(&p)
+    }
+
+    pub fn get_mut(&mut self, p: Point<T>) -> Option<&mut V> {
+        self.cells.get_mut(&p)
+    }
+
+    pub fn insert(&mut self, p: Point<T>, value: V) -> Option<V> {
+        self.cells.insert(p, value)
+    }
+
+    pub fn entry(&mut self, p: Point<T>) -> Entry<'_, Point<T>, V> {
+        self.cells.entry(p)
+    }
+
+    /// The set cells in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<T>, &V)> {
+        self.cells.iter().map(|(&p, v)| (p, v))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.cells.values()
+    }
+
+    /// The smallest box around the set cells, or `None` when the grid is empty.
+    pub fn bbox(&self) -> Option<BBox<T>> {
+        BBox::from_points(self.cells.keys().copied())
+    }
+
+    /// The 4-connected neighbours of `p` that are set.
+    pub fn neighbours4(&self, p: Point<T>) -> impl Iterator<Item = (Point<T>, &V)> {
+        p.neighbours4()
+            .filter_map(|n| self.cells.get(&n).map(|v| (n, v)))
+    }
+
+    /// The 8-connected neighbours of `p` that are set.
+    pub fn neighbours8(&self, p: Point<T>) -> impl Iterator<Item = (Point<T>, &V)> {
+        p.neighbours8()
+            .filter_map(|n| self.cells.get(&n).map(|v| (n, v)))
+    }
+}
+
+impl<V, T: Coord> FromIterator<(Point<T>, V)> for SparseGrid<V, T> {
+    fn from_iter<I: IntoIterator<Item = (Point<T>, V)>>(cells: I) -> Self {
+        SparseGrid {
+            cells: cells.into_iter().collect(),
+        }
+    }
+}
+
+impl<V, T: Coord> Index<Point<T>> for SparseGrid<V, T> {
+    type Output = V;
+
+    fn index(&self, p: Point<T>) -> &V {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{p} is not set in the grid"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn neighbours_stay_in_unsigned_range() {
+        let origin = Point::<u32>::new(0, 0);
+        let four: Vec<_> = origin.neighbours4().collect();
+        assert_eq!(four, vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(origin.neighbours8().count(), 3);
+        assert_eq!(Point::new(5, 5).neighbours8().count(), 8);
+    }
+
+    #[test]
+    fn point_distances() {
+        let a = Point::new(1, 6);
+        let b = Point::new(8, 3);
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 7);
+        assert_eq!(Point::<u32>::new(3, 0).manhattan(Point::new(0, 4)), 7);
+    }
+
+    #[test]
+    fn parse_point() {
+        assert_eq!("1, 6".parse::<Point>(), Ok(Point::new(1, 6)));
+        let e = "1, x".parse::<Point>().unwrap_err();
+        assert_eq!((e.start, e.end), (3, 4));
+    }
+
+    #[test]
+    fn bbox_from_points() {
+        let points = [Point::new(1, 1), Point::new(8, 3), Point::new(3, 9)];
+        let bbox = BBox::from_points(points).unwrap();
+        assert_eq!(bbox, BBox::new(Point::new(1, 1), Point::new(8, 9)));
+        assert_eq!((bbox.width(), bbox.height()), (8, 9));
+        assert!(bbox.on_border(Point::new(8, 5)));
+        assert!(!bbox.on_border(Point::new(7, 5)));
+        assert_eq!(bbox.expand(2).min, Point::new(-1, -1));
+        assert_eq!(BBox::<i32>::from_points([]), None);
+    }
+
+    #[test]
+    fn bbox_points_row_major() {
+        let bbox = BBox::from_size(Point::<u32>::new(1, 3), 2, 2);
+        let points: Vec<_> = bbox.points().collect();
+        assert_eq!(
+            points,
+            [(1, 3), (2, 3), (1, 4), (2, 4)].map(|(x, y)| Point::new(x, y))
+        );
+    }
+
+    #[test]
+    fn bbox_intersection() {
+        let a = BBox::from_size(Point::new(1, 3), 4, 4);
+        let b = BBox::from_size(Point::new(3, 1), 4, 4);
+        let c = BBox::from_size(Point::new(5, 5), 2, 2);
+        assert_eq!(a.intersection(&b).map(|i| i.area()), Some(4));
+        assert_eq!(a.intersection(&c), None);
+    }
+
+    #[test]
+    fn dense_grid_with_offset_origin() {
+        let bbox = BBox::new(Point::new(-2, -1), Point::new(2, 1));
+        let mut grid = Grid::new(bbox, 0);
+        grid[Point::new(-2, -1)] = 1;
+        grid[Point::new(2, 1)] = 2;
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.rows().next().unwrap(), &[1, 0, 0, 0, 0]);
+        assert_eq!(grid.iter().last(), Some((Point::new(2, 1), &2)));
+        assert_eq!(grid.neighbours4(Point::new(-2, 0)).count(), 3);
+    }
+
+    #[test]
+    fn parse_grid() {
+        let grid = Grid::parse("#.\n.#\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[Point::new(1, 1)], b'#');
+        assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 3);
+        let Err(Error::Parse(e)) = Grid::parse("##\n#\n") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(e.line, 2);
+        let empty = Grid::parse("").unwrap();
+        assert_eq!((empty.bbox(), empty.iter().count()), (None, 0));
+    }
+
+    #[test]
+    fn sparse_grid() {
+        let mut grid: SparseGrid<u32> = [(Point::new(0, 0), 1), (Point::new(1, 0), 2)]
+            .into_iter()
+            .collect();
+        *grid.entry(Point::new(-3, 4)).or_default() += 5;
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid[Point::new(-3, 4)], 5);
+        assert_eq!(grid.neighbours4(Point::new(0, 0)).count(), 1);
+        assert_eq!(
+            grid.bbox(),
+            Some(BBox::new(Point::new(-3, 0), Point::new(1, 4)))
+        );
+    }
+}
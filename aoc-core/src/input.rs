@@ -0,0 +1,76 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::Result;
+
+/// Location of the puzzle input, relative to a day's crate directory.
+pub const INPUT_PATH: &str = "input/input.txt";
+
+/// Path of the puzzle input for the day crate in `day_dir`.
+pub fn input_path(day_dir: &Path) -> PathBuf {
+    day_dir.join(INPUT_PATH)
+}
+
+/// Path of the `n`th worked example, stored next to the real input as
+/// `input/example<n>.txt`.
+pub fn example_path(day_dir: &Path, n: u32) -> PathBuf {
+    day_dir.join(format!("input/example{n}.txt"))
+}
+
+/// Reads the puzzle input for the day crate in `day_dir`.
+pub fn read_input(day_dir: &Path) -> Result<String> {
+    read_file(&input_path(day_dir))
+}
+
+/// Where a run takes its input from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// The day's own `input/input.txt`.
+    #[default]
+    Puzzle,
+    File(PathBuf),
+    Stdin,
+    /// One of the day's `input/example<n>.txt` files.
+    Example(u32),
+}
+
+impl InputSource {
+    /// Reads the input for the day crate in `day_dir`.
+    pub fn read(&self, day_dir: &Path) -> Result<String> {
+        match self {
+            InputSource::Puzzle => read_input(day_dir),
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            InputSource::Example(n) => read_file(&example_path(day_dir, *n)),
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|e| format!("failed to read {}: {e}", path.display()).into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example_lives_next_to_input() {
+        let dir = Path::new("2018/aoc4");
+        assert_eq!(example_path(dir, 2).parent(), input_path(dir).parent(),);
+        assert!(example_path(dir, 2).ends_with("input/example2.txt"));
+    }
+
+    #[test]
+    fn missing_file_names_the_path() {
+        let err = InputSource::Example(9)
+            .read(Path::new("no-such-day"))
+            .unwrap_err();
+        assert!(err.to_string().contains("example9.txt"));
+    }
+}
@@ -0,0 +1,24 @@
+//! Shared building blocks for the Advent of Code solutions in this workspace.
+//!
+//! Every day crate depends on this library for its error type, input loading
+//! and the small parsing helpers that used to be copy-pasted between days. The
+//! `aoc` binary uses the [`runner`] module to solve and time any registered day.
+
+pub mod bench;
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod parallel;
+pub mod params;
+pub mod parse;
+pub mod runner;
+pub mod similarity;
+pub mod solution;
+#[doc(hidden)]
+pub mod testing;
+
+pub use error::{Error, LineError, ParseError};
+pub use solution::{Answer, Solution};
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,60 @@
+//! A process-wide thread budget for days that split their work, set by the
+//! runner's `--jobs` option.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// Zero means "not set": use the machine's available parallelism.
+static JOBS: AtomicUsize = AtomicUsize::new(0);
+
+/// Caps the number of threads used by [`map`]. Zero restores the default.
+pub fn set_jobs(jobs: usize) {
+    JOBS.store(jobs, Ordering::Relaxed);
+}
+
+/// The number of threads [`map`] may use.
+pub fn jobs() -> usize {
+    match JOBS.load(Ordering::Relaxed) {
+        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+        jobs => jobs,
+    }
+}
+
+/// Applies `f` to every item on up to [`jobs`] threads and returns the results
+/// in the order of `items`.
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let jobs = jobs().min(items.len());
+    if jobs <= 1 {
+        return items.iter().map(f).collect();
+    }
+    let chunk = items.len().div_ceil(jobs);
+    let f = &f;
+    thread::scope(|scope| {
+        let workers: Vec<_> = items
+            .chunks(chunk)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(f).collect::<Vec<R>>()))
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("worker thread panicked"))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn map_keeps_order() {
+        let items: Vec<u32> = (0..100).collect();
+        let squares = map(&items, |n| n * n);
+        assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+        assert!(map(&[] as &[u32], |n| *n).is_empty());
+    }
+}
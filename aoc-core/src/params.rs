@@ -0,0 +1,57 @@
+//! Named puzzle parameters that can be overridden for a whole run, set by the
+//! runner's `--param name=value` option.
+//!
+//! Days keep their own defaults and only consult this for values the puzzle
+//! text treats as adjustable, such as a distance threshold.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+static PARAMS: Mutex<BTreeMap<String, i64>> = Mutex::new(BTreeMap::new());
+
+/// Overrides `name` for every later [`get`].
+pub fn set(name: &str, value: i64) {
+    PARAMS
+        .lock()
+        .expect("parameters are never poisoned")
+        .insert(name.to_string(), value);
+}
+
+/// The override for `name`, if one was set.
+pub fn get(name: &str) -> Option<i64> {
+    PARAMS
+        .lock()
+        .expect("parameters are never poisoned")
+        .get(name)
+        .copied()
+}
+
+/// Splits a `name=value` argument.
+pub fn parse_assignment(arg: &str) -> Result<(String, i64), String> {
+    let (name, value) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("expected `name=value`, found `{arg}`"))?;
+    let value = value
+        .trim()
+        .parse()
+        .map_err(|e| format!("bad value for `{}`: {e}", name.trim()))?;
+    Ok((name.trim().to_string(), value))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn overrides_are_read_back() {
+        assert_eq!(get("params-test"), None);
+        set("params-test", 32);
+        assert_eq!(get("params-test"), Some(32));
+        assert_eq!(
+            parse_assignment("threshold=32"),
+            Ok(("threshold".to_string(), 32))
+        );
+        assert!(parse_assignment("threshold").is_err());
+        assert!(parse_assignment("threshold=x").is_err());
+    }
+}
@@ -0,0 +1,128 @@
+use std::any::Any;
+use std::str::FromStr;
+
+use crate::Result;
+use crate::error::{LineError, ParseError};
+
+/// Parses every non-empty line of `input` as a `T`, stopping at the first failure.
+///
+/// When `T`'s error is a [`LineError`] its span is kept; any other error is
+/// reported against the whole line.
+pub fn lines<T>(input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: std::error::Error + 'static,
+{
+    lines_with(input, |line| line.parse::<T>().map_err(into_line_error))
+}
+
+/// Like [`lines`], with a custom parser for each trimmed, non-empty line.
+/// Spans in the returned [`LineError`] are relative to the trimmed line.
+pub fn lines_with<T, F>(input: &str, mut parse: F) -> Result<Vec<T>>
+where
+    F: FnMut(&str) -> std::result::Result<T, LineError>,
+{
+    let mut values = vec![];
+    for (index, text) in input.lines().enumerate() {
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let indent = text.len() - text.trim_start().len();
+        match parse(trimmed) {
+            Ok(value) => values.push(value),
+            Err(e) => return Err(ParseError::new(index + 1, text, e.shifted(indent)).into()),
+        }
+    }
+    Ok(values)
+}
+
+/// Parses every non-empty line of `input` as `N` whitespace-separated `T`s and
+/// returns them column by column. A missing or extra field is an error, as is
+/// a field that doesn't parse.
+pub fn columns<T, const N: usize>(input: &str) -> Result<[Vec<T>; N]>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    let mut columns: [Vec<T>; N] = std::array::from_fn(|_| vec![]);
+    lines_with(input, |line| {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let expected = format!("expected {N} columns, found {}", fields.len());
+        match fields.get(N) {
+            Some(extra) => return Err(LineError::token(line, extra, expected)),
+            None if fields.len() < N => return Err(LineError::new(expected)),
+            None => {}
+        }
+        for (column, field) in columns.iter_mut().zip(fields) {
+            let value = field
+                .parse()
+                .map_err(|e: T::Err| LineError::token(line, field, e.to_string()))?;
+            column.push(value);
+        }
+        Ok(())
+    })?;
+    Ok(columns)
+}
+
+fn into_line_error<E: std::error::Error + 'static>(error: E) -> LineError {
+    match (&error as &dyn Any).downcast_ref::<LineError>() {
+        Some(e) => e.clone(),
+        None => LineError::new(error.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn lines_skips_blank_lines() {
+        let values: Vec<i32> = lines("+1\n-2\n\n3\n").unwrap();
+        assert_eq!(values, vec![1, -2, 3]);
+    }
+
+    #[test]
+    fn lines_reports_bad_line() {
+        let Err(Error::Parse(e)) = lines::<i32>("1\n\n  x7\n") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column, e.width), (3, 3, 2));
+        assert_eq!(e.text, "  x7");
+    }
+
+    #[test]
+    fn lines_with_keeps_span() {
+        let result = lines_with("ab cd", |line| {
+            let token = line.split_whitespace().nth(1).unwrap();
+            Err::<(), _>(LineError::token(line, token, "bad token"))
+        });
+        let Err(Error::Parse(e)) = result else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.column, e.width), (4, 2));
+        assert_eq!(e.message, "bad token");
+    }
+
+    #[test]
+    fn columns_split_fields() {
+        let [left, right] = columns::<i32, 2>("3   4\n\n  -1 7\n").unwrap();
+        assert_eq!((left, right), (vec![3, -1], vec![4, 7]));
+    }
+
+    #[test]
+    fn columns_report_field_count_and_bad_fields() {
+        for (input, span, message) in [
+            ("1 2\n3", (2, 1, 1), "expected 2 columns, found 1"),
+            ("1 2 3", (1, 5, 1), "expected 2 columns, found 3"),
+            ("1 2\n4 y5", (2, 3, 2), "invalid digit found in string"),
+        ] {
+            let Err(Error::Parse(e)) = columns::<i32, 2>(input) else {
+                panic!("expected a parse error for {input:?}");
+            };
+            assert_eq!((e.line, e.column, e.width), span, "{input:?}");
+            assert_eq!(e.message, message);
+        }
+    }
+}
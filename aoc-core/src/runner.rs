@@ -0,0 +1,161 @@
+use std::any::Any;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::{Answer, Result, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "part1"),
+            Part::Two => write!(f, "part2"),
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = crate::Error;
+
+    fn try_from(n: u8) -> Result<Self> {
+        match n {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("there is no part {n}").into()),
+        }
+    }
+}
+
+/// Object-safe view of a [`Solution`], so days with different parsed types
+/// can sit side by side in a registry.
+pub trait DynSolution: Sync {
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>>;
+    fn solve_any(&self, part: Part, parsed: &dyn Any) -> Result<Answer>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+    S::Parsed: 'static,
+{
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(self.parse(input)?))
+    }
+
+    fn solve_any(&self, part: Part, parsed: &dyn Any) -> Result<Answer> {
+        let parsed = parsed
+            .downcast_ref::<S::Parsed>()
+            .ok_or("parsed input has the wrong type for this solution")?;
+        match part {
+            Part::One => self.part1(parsed),
+            Part::Two => self.part2(parsed),
+        }
+    }
+}
+
+/// Answer to one part together with how long it took to compute.
+#[derive(Debug)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub parse_time: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+impl Report {
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.parts.iter().map(|p| p.elapsed).sum::<Duration>()
+    }
+}
+
+/// Parses `input` once and solves each of `parts`, timing every phase.
+///
+/// A parse failure aborts the run; a failing part is recorded in its report
+/// so the remaining parts still get a chance to run.
+pub fn run(solution: &dyn DynSolution, input: &str, parts: &[Part]) -> Result<Report> {
+    let start = Instant::now();
+    let parsed = solution.parse_any(input)?;
+    let parse_time = start.elapsed();
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = solution.solve_any(part, parsed.as_ref());
+            PartReport {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+    Ok(Report { parse_time, parts })
+}
+
+/// Formats a duration with a unit suited to its size, e.g. `812µs` or `1.24s`.
+pub fn format_duration(d: Duration) -> String {
+    let micros = d.as_micros();
+    if micros < 1_000 {
+        format!("{micros}µs")
+    } else if micros < 1_000_000 {
+        format!("{:.2}ms", d.as_secs_f64() * 1e3)
+    } else {
+        format!("{:.2}s", d.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed = Vec<i64>;
+
+        fn parse(&self, input: &str) -> Result<Self::Parsed> {
+            crate::parse::lines(input)
+        }
+
+        fn part1(&self, values: &Self::Parsed) -> Result<Answer> {
+            Ok(values.iter().sum::<i64>().into())
+        }
+
+        fn part2(&self, _values: &Self::Parsed) -> Result<Answer> {
+            Err("no part 2".into())
+        }
+    }
+
+    #[test]
+    fn run_reports_each_part() -> Result<()> {
+        let report = run(&Sum, "1\n2\n3", &Part::BOTH)?;
+        assert_eq!(report.parts.len(), 2);
+        assert_eq!(report.parts[0].answer.as_ref().unwrap(), &Answer::Int(6));
+        assert!(report.parts[1].answer.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn run_fails_on_bad_input() {
+        assert!(run(&Sum, "1\nx", &Part::BOTH).is_err());
+    }
+
+    #[test]
+    fn part_from_number() {
+        assert_eq!(Part::try_from(2).unwrap(), Part::Two);
+        assert!(Part::try_from(3).is_err());
+    }
+}
@@ -0,0 +1,268 @@
+//! String distances and a BK-tree for "everything within `k` of this" queries.
+
+/// How far apart two strings are. Both metrics count in chars, not bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// Positions that differ, with each char past the end of the shorter
+    /// string counting as a difference.
+    Hamming,
+    /// Single-char insertions, deletions and substitutions.
+    Levenshtein,
+}
+
+impl Metric {
+    pub fn distance(self, a: &str, b: &str) -> usize {
+        match self {
+            Metric::Hamming => hamming(a, b),
+            Metric::Levenshtein => levenshtein(a, b),
+        }
+    }
+}
+
+pub fn hamming(a: &str, b: &str) -> usize {
+    let (mut a, mut b) = (a.chars(), b.chars());
+    let mut distance = 0;
+    loop {
+        match (a.next(), b.next()) {
+            (None, None) => return distance,
+            (Some(x), Some(y)) if x == y => {}
+            _ => distance += 1,
+        }
+    }
+}
+
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, x) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, &y) in b.iter().enumerate() {
+            let substitute = previous[j] + usize::from(x != y);
+            current[j + 1] = substitute.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// The chars that `a` and `b` have in the same positions, in order.
+pub fn common_letters(a: &str, b: &str) -> String {
+    a.chars()
+        .zip(b.chars())
+        .filter(|(x, y)| x == y)
+        .map(|(x, _)| x)
+        .collect()
+}
+
+/// A Burkhard-Keller tree: each child edge is labelled with its distance from
+/// the parent, so the triangle inequality rules out whole subtrees in a query.
+#[derive(Debug, Clone)]
+pub struct BkTree {
+    metric: Metric,
+    nodes: Vec<BkNode>,
+}
+
+#[derive(Debug, Clone)]
+struct BkNode {
+    word: String,
+    /// `(distance from this word, node index)`.
+    children: Vec<(usize, usize)>,
+}
+
+impl BkTree {
+    pub fn new(metric: Metric) -> Self {
+        BkTree {
+            metric,
+            nodes: vec![],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Adds `word`, returning `false` if it was already present.
+    pub fn insert(&mut self, word: &str) -> bool {
+        let new = self.nodes.len();
+        if new > 0 {
+            let mut node = 0;
+            loop {
+                let distance = self.metric.distance(word, &self.nodes[node].word);
+                if distance == 0 {
+                    return false;
+                }
+                let children = &mut self.nodes[node].children;
+                match children.iter().find(|(d, _)| *d == distance) {
+                    Some(&(_, child)) => node = child,
+                    None => {
+                        children.push((distance, new));
+                        break;
+                    }
+                }
+            }
+        }
+        self.nodes.push(BkNode {
+            word: word.to_string(),
+            children: vec![],
+        });
+        true
+    }
+
+    /// The words within distance `k` of `query`, with their distances, in the
+    /// order they were inserted.
+    pub fn within(&self, query: &str, k: usize) -> Vec<(&str, usize)> {
+        let mut found = vec![];
+        let mut stack = if self.nodes.is_empty() {
+            vec![]
+        } else {
+            vec![0]
+        };
+        while let Some(node) = stack.pop() {
+            let BkNode { word, children } = &self.nodes[node];
+            let distance = self.metric.distance(query, word);
+            if distance <= k {
+                found.push((node, distance));
+            }
+            let range = distance.saturating_sub(k)..=distance + k;
+            stack.extend(
+                children
+                    .iter()
+                    .filter(|(d, _)| range.contains(d))
+                    .map(|&(_, child)| child),
+            );
+        }
+        found.sort_unstable();
+        found
+            .into_iter()
+            .map(|(node, distance)| (self.nodes[node].word.as_str(), distance))
+            .collect()
+    }
+
+    /// Groups the words into families, where two words are in the same family
+    /// if a chain of words each within `k` of the next joins them. Families
+    /// and their members are in insertion order.
+    pub fn clusters(&self, k: usize) -> Vec<Vec<&str>> {
+        let mut family = vec![usize::MAX; self.nodes.len()];
+        let mut families = vec![];
+        for start in 0..self.nodes.len() {
+            if family[start] != usize::MAX {
+                continue;
+            }
+            let id = families.len();
+            family[start] = id;
+            let mut members = vec![start];
+            let mut stack = vec![start];
+            while let Some(node) = stack.pop() {
+                for (word, _) in self.within(&self.nodes[node].word, k) {
+                    let neighbour = self.position(word);
+                    if family[neighbour] == usize::MAX {
+                        family[neighbour] = id;
+                        members.push(neighbour);
+                        stack.push(neighbour);
+                    }
+                }
+            }
+            members.sort_unstable();
+            families.push(members);
+        }
+        families
+            .into_iter()
+            .map(|members| {
+                members
+                    .into_iter()
+                    .map(|node| self.nodes[node].word.as_str())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The node holding `word`, which must be in the tree.
+    fn position(&self, word: &str) -> usize {
+        let mut node = 0;
+        loop {
+            let distance = self.metric.distance(word, &self.nodes[node].word);
+            if distance == 0 {
+                return node;
+            }
+            node = self.nodes[node]
+                .children
+                .iter()
+                .find(|(d, _)| *d == distance)
+                .map(|&(_, child)| child)
+                .expect("word is in the tree");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Every string of length 0 to 4 over `ab`.
+    fn words() -> Vec<String> {
+        let mut words = vec![String::new()];
+        let mut previous = vec![String::new()];
+        for _ in 0..4 {
+            previous = previous
+                .iter()
+                .flat_map(|w| [format!("{w}a"), format!("{w}b")])
+                .collect();
+            words.extend(previous.iter().cloned());
+        }
+        words
+    }
+
+    #[test]
+    fn distances() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(hamming("abcde", "axcye"), 2);
+        assert_eq!(hamming("abc", "abcde"), 2);
+        assert_eq!(common_letters("fghij", "fguij"), "fgij");
+    }
+
+    #[test]
+    fn within_matches_brute_force() {
+        let words = words();
+        for metric in [Metric::Hamming, Metric::Levenshtein] {
+            let mut tree = BkTree::new(metric);
+            for w in &words {
+                assert!(tree.insert(w));
+            }
+            assert!(!tree.insert("ab"));
+            for query in ["", "ab", "abba", "bbbbb"] {
+                for k in 0..=3 {
+                    let expected: Vec<(&str, usize)> = words
+                        .iter()
+                        .map(|w| (w.as_str(), metric.distance(query, w)))
+                        .filter(|(_, d)| *d <= k)
+                        .collect();
+                    assert_eq!(tree.within(query, k), expected, "{metric:?} {query} {k}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn clusters_chain_through_neighbours() {
+        let mut tree = BkTree::new(Metric::Levenshtein);
+        for word in ["abcde", "fghij", "abcdx", "zzzzz", "abcxx", "fghix"] {
+            tree.insert(word);
+        }
+        assert_eq!(
+            tree.clusters(1),
+            vec![
+                vec!["abcde", "abcdx", "abcxx"],
+                vec!["fghij", "fghix"],
+                vec!["zzzzz"]
+            ]
+        );
+        assert_eq!(tree.clusters(0).len(), 6);
+    }
+}
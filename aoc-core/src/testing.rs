@@ -0,0 +1,39 @@
+//! Helpers for the days' randomized tests.
+
+/// A small seeded generator, so randomized tests are repeatable: the same
+/// seed always yields the same fixtures.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    /// A number in `0..bound`. `bound` must be positive.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.state = self
+            .state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.state >> 33) % bound
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let draw = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..20).map(|_| rng.below(10)).collect::<Vec<_>>()
+        };
+        assert_eq!(draw(7), draw(7));
+        assert_ne!(draw(7), draw(8));
+        assert!(draw(7).iter().all(|&n| n < 10));
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_core::runner::Part;
+use aoc_core::{Answer, Error, Result};
+use serde::{Deserialize, Serialize};
+
+/// An accepted answer as stored in `answers.toml`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Recorded {
+    Int(i64),
+    Text(String),
+}
+
+impl Recorded {
+    /// `None` for answers that cannot be recorded because there is nothing to check yet.
+    pub fn from_answer(answer: &Answer) -> Option<Self> {
+        match answer {
+            Answer::Int(n) => Some(Recorded::Int(*n)),
+            Answer::Text(_) | Answer::Grid(_) => Some(Recorded::Text(answer.to_string())),
+            Answer::Unsolved => None,
+        }
+    }
+
+    pub fn matches(&self, answer: &Answer) -> bool {
+        Recorded::from_answer(answer).as_ref() == Some(self)
+    }
+}
+
+impl std::fmt::Display for Recorded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Recorded::Int(n) => write!(f, "{n}"),
+            Recorded::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Recorded>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Recorded>,
+}
+
+impl DayAnswers {
+    pub fn get(&self, part: Part) -> Option<&Recorded> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    fn set(&mut self, part: Part, answer: Recorded) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+}
+
+/// The accepted answers for one year, kept in `<year>/answers.toml` with one
+/// table per day.
+pub struct AnswerBook {
+    path: PathBuf,
+    days: BTreeMap<u8, DayAnswers>,
+}
+
+impl AnswerBook {
+    pub fn path(root: &Path, year: u16) -> PathBuf {
+        root.join(year.to_string()).join("answers.toml")
+    }
+
+    pub fn load(path: PathBuf) -> Result<Self> {
+        let days = match fs::read_to_string(&path) {
+            Ok(text) => {
+                toml::from_str(&text).map_err(|e| format!("invalid {}: {e}", path.display()))?
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(AnswerBook { path, days })
+    }
+
+    pub fn day(&self, day: u8) -> Option<&DayAnswers> {
+        self.days.get(&day)
+    }
+
+    /// Records an accepted answer and writes the file back.
+    pub fn record(&mut self, day: u8, part: Part, answer: Recorded) -> Result<()> {
+        self.days.entry(day).or_default().set(part, answer);
+        fs::write(
+            &self.path,
+            toml::to_string(&self.days).map_err(Error::other)?,
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn recorded_matches_answers() {
+        assert!(Recorded::Int(42).matches(&Answer::Int(42)));
+        assert!(!Recorded::Int(42).matches(&Answer::Text("42".to_string())));
+        assert!(
+            Recorded::Text("#.\n.#".to_string())
+                .matches(&Answer::Grid(vec!["#.".to_string(), ".#".to_string()]))
+        );
+        assert!(!Recorded::Int(1).matches(&Answer::Unsolved));
+    }
+
+    #[test]
+    fn record_round_trips() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        fs::create_dir_all(dir.join("2018"))?;
+        let path = AnswerBook::path(&dir, 2018);
+        let mut book = AnswerBook::load(path.clone())?;
+        book.record(10, Part::One, Recorded::Int(7))?;
+        book.record(2, Part::Two, Recorded::Text("abc".to_string()))?;
+        let text = fs::read_to_string(&path)?;
+        assert!(text.find("[2]").unwrap() < text.find("[10]").unwrap());
+        let reloaded = AnswerBook::load(path)?;
+        assert_eq!(reloaded.day(10).unwrap().part1, Some(Recorded::Int(7)));
+        assert_eq!(
+            reloaded.day(2).unwrap().get(Part::Two),
+            Some(&Recorded::Text("abc".to_string()))
+        );
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
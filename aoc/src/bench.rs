@@ -0,0 +1,189 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use aoc_core::bench::Stats;
+use aoc_core::{Error, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// One benchmarked phase as stored in the history file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    /// Short hash of the commit that was measured, with `-dirty` for uncommitted changes.
+    pub commit: String,
+    pub at: DateTime<Utc>,
+    pub year: u16,
+    pub day: u8,
+    /// `parse`, `part1` or `part2`.
+    pub phase: String,
+    pub runs: usize,
+    pub median_ns: u64,
+    pub mad_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Record {
+    pub fn new(
+        commit: &str,
+        at: DateTime<Utc>,
+        year: u16,
+        day: u8,
+        phase: &str,
+        stats: Stats,
+    ) -> Self {
+        let ns = |d: std::time::Duration| d.as_nanos() as u64;
+        Record {
+            commit: commit.to_string(),
+            at,
+            year,
+            day,
+            phase: phase.to_string(),
+            runs: stats.runs,
+            median_ns: ns(stats.median),
+            mad_ns: ns(stats.mad),
+            min_ns: ns(stats.min),
+            max_ns: ns(stats.max),
+        }
+    }
+}
+
+/// Past benchmark results, one JSON object per line.
+pub struct History {
+    path: PathBuf,
+    records: Vec<Record>,
+}
+
+impl History {
+    pub fn path(root: &Path) -> PathBuf {
+        root.join(".bench-history.jsonl")
+    }
+
+    pub fn load(path: PathBuf) -> Result<Self> {
+        let records = match fs::read_to_string(&path) {
+            Ok(text) => text
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(serde_json::from_str)
+                .collect::<std::result::Result<_, _>>()
+                .map_err(|e| format!("corrupt bench history {}: {e}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => vec![],
+            Err(e) => return Err(e.into()),
+        };
+        Ok(History { path, records })
+    }
+
+    /// The most recent result for the same phase measured on a different commit.
+    pub fn previous(&self, record: &Record) -> Option<&Record> {
+        self.records.iter().rev().find(|r| {
+            r.year == record.year
+                && r.day == record.day
+                && r.phase == record.phase
+                && r.commit != record.commit
+        })
+    }
+
+    pub fn append(&mut self, records: Vec<Record>) -> Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        for record in records {
+            writeln!(
+                file,
+                "{}",
+                serde_json::to_string(&record).map_err(Error::other)?
+            )?;
+            self.records.push(record);
+        }
+        Ok(())
+    }
+}
+
+/// Relative change of `current` against `previous`, e.g. `+12.5%`.
+pub fn change(previous: &Record, current: &Record) -> String {
+    if previous.median_ns == 0 {
+        return "n/a".to_string();
+    }
+    let ratio = current.median_ns as f64 / previous.median_ns as f64 - 1.0;
+    format!("{:+.1}%", ratio * 100.0)
+}
+
+/// Identifies the checked-out commit, or `unknown` outside a git checkout.
+pub fn current_commit(root: &Path) -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(root)
+            .output()
+            .ok()
+            .filter(|out| out.status.success())
+            .map(|out| String::from_utf8_lossy(&out.stdout).trim().to_string())
+    };
+    let Some(hash) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(status) if !status.is_empty() => format!("{hash}-dirty"),
+        _ => hash,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration;
+
+    fn record(commit: &str, phase: &str, median_ms: u64) -> Record {
+        let ms = Duration::from_millis(median_ms);
+        let stats = Stats {
+            runs: 3,
+            median: ms,
+            mad: Duration::ZERO,
+            min: ms,
+            max: ms,
+        };
+        Record::new(commit, DateTime::UNIX_EPOCH, 2018, 4, phase, stats)
+    }
+
+    #[test]
+    fn previous_skips_same_commit_and_other_phases() {
+        let history = History {
+            path: PathBuf::new(),
+            records: vec![
+                record("aaa", "part1", 10),
+                record("bbb", "part2", 10),
+                record("ccc", "part1", 20),
+            ],
+        };
+        let current = record("ccc", "part1", 25);
+        assert_eq!(history.previous(&current).unwrap().commit, "aaa");
+        assert!(history.previous(&record("ddd", "parse", 1)).is_none());
+    }
+
+    #[test]
+    fn change_is_relative_to_previous() {
+        assert_eq!(
+            change(&record("a", "part1", 20), &record("b", "part1", 25)),
+            "+25.0%"
+        );
+        assert_eq!(
+            change(&record("a", "part1", 20), &record("b", "part1", 15)),
+            "-25.0%"
+        );
+    }
+
+    #[test]
+    fn history_round_trips() -> Result<()> {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let mut history = History::load(path.clone())?;
+        history.append(vec![record("aaa", "parse", 1), record("aaa", "part1", 2)])?;
+        let reloaded = History::load(path.clone())?;
+        assert_eq!(reloaded.records, history.records);
+        fs::remove_file(&path)?;
+        Ok(())
+    }
+}
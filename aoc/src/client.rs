@@ -0,0 +1,184 @@
+use aoc_core::Result;
+use aoc_core::runner::Part;
+
+use crate::http::{HttpBackend, Method, Request, Response, USER_AGENT, UreqBackend, form_encode};
+use crate::submit::{Reply, part_number};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the adventofcode.com `session` cookie.
+pub const SESSION_ENV: &str = "AOC_SESSION_COOKIE";
+
+/// Talks to adventofcode.com, or to whatever `base_url` points at.
+pub struct AocClient<B: HttpBackend> {
+    backend: B,
+    base_url: String,
+    session: String,
+}
+
+impl AocClient<UreqBackend> {
+    /// Client for the real site, authenticated with the cookie in [`SESSION_ENV`].
+    /// `AOC_BASE_URL` overrides the site, e.g. to point at a local stand-in.
+    pub fn from_env() -> Result<Self> {
+        let session = std::env::var(SESSION_ENV)
+            .map_err(|_| format!("set {SESSION_ENV} to your adventofcode.com session cookie"))?;
+        let base_url =
+            std::env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Ok(AocClient::new(UreqBackend::new(), base_url, session))
+    }
+}
+
+impl<B: HttpBackend> AocClient<B> {
+    pub fn new(backend: B, base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        AocClient {
+            backend,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+        }
+    }
+
+    fn send(&self, method: Method, path: &str, body: Option<String>) -> Result<Response> {
+        let request = Request {
+            method,
+            url: format!("{}{path}", self.base_url),
+            headers: vec![
+                ("Cookie".to_string(), format!("session={}", self.session)),
+                ("User-Agent".to_string(), USER_AGENT.to_string()),
+            ],
+            body,
+        };
+        self.backend.send(&request)
+    }
+
+    /// Downloads the puzzle input, rejecting anything that is not plausibly an input.
+    pub fn download_input(&self, year: u16, day: u8) -> Result<String> {
+        let response = self.send(Method::Get, &format!("/{year}/day/{day}/input"), None)?;
+        validate_input(year, day, response)
+    }
+
+    /// Posts `answer` for one part and classifies the site's reply.
+    pub fn submit_answer(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<Reply> {
+        let body = format!("level={}&answer={}", part_number(part), form_encode(answer));
+        let response = self.send(
+            Method::Post,
+            &format!("/{year}/day/{day}/answer"),
+            Some(body),
+        )?;
+        if looks_like_login_page(&response.body) && !response.body.contains("<article>") {
+            return Err(format!(
+                "adventofcode.com did not accept the session cookie; refresh {SESSION_ENV}"
+            )
+            .into());
+        }
+        if !(200..300).contains(&response.status) {
+            return Err(format!(
+                "unexpected status {} submitting {year} day {day}",
+                response.status
+            )
+            .into());
+        }
+        Ok(Reply::parse(&response.body))
+    }
+}
+
+fn validate_input(year: u16, day: u8, response: Response) -> Result<String> {
+    let body = response.body;
+    if response.status == 404 {
+        return Err(
+            format!("{year} day {day} has no input (404); has the puzzle unlocked?").into(),
+        );
+    }
+    if looks_like_login_page(&body) {
+        return Err(format!(
+            "adventofcode.com did not accept the session cookie (status {}); refresh {SESSION_ENV}",
+            response.status
+        )
+        .into());
+    }
+    if !(200..300).contains(&response.status) {
+        return Err(format!(
+            "unexpected status {} fetching {year} day {day}",
+            response.status
+        )
+        .into());
+    }
+    if body.trim().is_empty() {
+        return Err(format!("input for {year} day {day} is empty").into());
+    }
+    Ok(body)
+}
+
+fn looks_like_login_page(body: &str) -> bool {
+    let trimmed = body.trim_start();
+    let head: String = trimmed
+        .chars()
+        .take(200)
+        .collect::<String>()
+        .to_ascii_lowercase();
+    head.starts_with("<!doctype") || head.contains("<html") || body.contains("Please log in")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_server::TestServer;
+
+    fn response(status: u16, body: &str) -> Response {
+        Response {
+            status,
+            body: body.to_string(),
+        }
+    }
+
+    #[test]
+    fn accepts_plain_input() {
+        let input = validate_input(2018, 1, response(200, "+1\n-2\n")).unwrap();
+        assert_eq!(input, "+1\n-2\n");
+    }
+
+    #[test]
+    fn rejects_login_page() {
+        let html = "<!DOCTYPE html>\n<html lang=\"en-us\"><body>[Log In]</body></html>";
+        let err = validate_input(2018, 1, response(200, html)).unwrap_err();
+        assert!(err.to_string().contains(SESSION_ENV));
+        let short = "\n<!DOCTYPE html>";
+        assert!(validate_input(2018, 1, response(200, short)).is_err());
+        let text = "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n";
+        let err = validate_input(2018, 1, response(400, text)).unwrap_err();
+        assert!(err.to_string().contains(SESSION_ENV));
+    }
+
+    #[test]
+    fn rejects_missing_day() {
+        let err = validate_input(2030, 1, response(404, "Not Found")).unwrap_err();
+        assert!(err.to_string().contains("404"));
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        assert!(validate_input(2018, 1, response(200, "\n")).is_err());
+    }
+
+    #[test]
+    fn download_sends_cookie_and_user_agent() -> Result<()> {
+        let server = TestServer::start(|request| {
+            assert_eq!(request.path, "/2018/day/5/input");
+            (200, "dabAcCaCBAcCcaDA\n".to_string())
+        });
+        let client = AocClient::new(UreqBackend::new(), server.url(), "secret");
+        assert_eq!(client.download_input(2018, 5)?, "dabAcCaCBAcCcaDA\n");
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert!(requests[0].body.is_empty());
+        assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+        let agents: Vec<&str> = requests[0]
+            .headers
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case("user-agent"))
+            .map(|(_, value)| value.as_str())
+            .collect();
+        assert_eq!(agents, vec![USER_AGENT]);
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_core::Result;
+
+use crate::client::AocClient;
+use crate::http::HttpBackend;
+
+/// Downloaded inputs, one file per day, so each input is requested from the site only once.
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        InputCache { dir: dir.into() }
+    }
+
+    pub fn from_env() -> Result<Self> {
+        Ok(InputCache::new(cache_dir()?))
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{day}.txt"))
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<String> {
+        fs::read_to_string(self.path(year, day)).ok()
+    }
+
+    pub fn put(&self, year: u16, day: u8, input: &str) -> Result<()> {
+        let path = self.path(year, day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, input)?;
+        Ok(())
+    }
+}
+
+/// Directory for downloaded inputs and the guess log: `AOC_CACHE_DIR`,
+/// falling back to `$XDG_CACHE_HOME/aoc` and then `~/.cache/aoc`.
+pub fn cache_dir() -> Result<PathBuf> {
+    let var = |name| std::env::var_os(name).map(PathBuf::from);
+    let dir = var("AOC_CACHE_DIR")
+        .or_else(|| var("XDG_CACHE_HOME").map(|d| d.join("aoc")))
+        .or_else(|| var("HOME").map(|d| d.join(".cache/aoc")))
+        .ok_or("cannot locate a cache directory; set AOC_CACHE_DIR")?;
+    Ok(dir)
+}
+
+/// Returns the input for `year`/`day`, downloading it only when it is not
+/// cached yet or `force` is set.
+pub fn fetch_input<B: HttpBackend>(
+    client: &AocClient<B>,
+    cache: &InputCache,
+    year: u16,
+    day: u8,
+    force: bool,
+) -> Result<String> {
+    if !force && let Some(input) = cache.get(year, day) {
+        return Ok(input);
+    }
+    let input = client.download_input(year, day)?;
+    cache.put(year, day, &input)?;
+    Ok(input)
+}
+
+/// Writes `input` to `path`, creating its directory if needed.
+pub fn write_input(path: &Path, input: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, input)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::http::UreqBackend;
+    use crate::test_server::TestServer;
+
+    fn temp_cache(name: &str) -> (InputCache, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        (InputCache::new(dir.clone()), dir)
+    }
+
+    #[test]
+    fn second_fetch_hits_cache() -> Result<()> {
+        let server = TestServer::start(|_| (200, "1\n2\n".to_string()));
+        let client = AocClient::new(UreqBackend::new(), server.url(), "secret");
+        let (cache, dir) = temp_cache("hit");
+        assert_eq!(fetch_input(&client, &cache, 2018, 1, false)?, "1\n2\n");
+        assert_eq!(fetch_input(&client, &cache, 2018, 1, false)?, "1\n2\n");
+        assert_eq!(server.requests().len(), 1);
+        fetch_input(&client, &cache, 2018, 1, true)?;
+        assert_eq!(server.requests().len(), 2);
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn rejected_download_is_not_cached() {
+        let server = TestServer::start(|_| (200, "<!DOCTYPE html><html></html>".to_string()));
+        let client = AocClient::new(UreqBackend::new(), server.url(), "expired");
+        let (cache, dir) = temp_cache("reject");
+        assert!(fetch_input(&client, &cache, 2018, 2, false).is_err());
+        assert!(cache.get(2018, 2).is_none());
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,105 @@
+use aoc_core::{Error, Result};
+
+/// Identifies the tool to adventofcode.com, as its maintainers ask automated clients to do.
+pub const USER_AGENT: &str = concat!(
+    "github.com/fox-daniel/advent-of-code aoc/",
+    env!("CARGO_PKG_VERSION")
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    /// Form-encoded body, only sent with [`Method::Post`].
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends HTTP requests for the client. Error statuses are returned as
+/// responses rather than errors so callers can inspect the body.
+pub trait HttpBackend {
+    fn send(&self, request: &Request) -> Result<Response>;
+}
+
+/// Default backend for talking to the real site.
+pub struct UreqBackend {
+    agent: ureq::Agent,
+}
+
+impl UreqBackend {
+    pub fn new() -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .build()
+            .into();
+        UreqBackend { agent }
+    }
+}
+
+impl HttpBackend for UreqBackend {
+    fn send(&self, request: &Request) -> Result<Response> {
+        let mut response = match request.method {
+            Method::Get => {
+                let mut builder = self.agent.get(&request.url);
+                for (name, value) in &request.headers {
+                    builder = builder.header(name, value);
+                }
+                builder.call().map_err(Error::other)?
+            }
+            Method::Post => {
+                let mut builder = self
+                    .agent
+                    .post(&request.url)
+                    .header("Content-Type", "application/x-www-form-urlencoded");
+                for (name, value) in &request.headers {
+                    builder = builder.header(name, value);
+                }
+                builder
+                    .send(request.body.as_deref().unwrap_or_default())
+                    .map_err(Error::other)?
+            }
+        };
+        Ok(Response {
+            status: response.status().as_u16(),
+            body: response.body_mut().read_to_string().map_err(Error::other)?,
+        })
+    }
+}
+
+/// Percent-encodes `value` for use in an `application/x-www-form-urlencoded` body.
+pub fn form_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            b' ' => encoded.push('+'),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn form_encode_escapes_reserved_bytes() {
+        assert_eq!(form_encode("abc-123"), "abc-123");
+        assert_eq!(form_encode("a b&c=d"), "a+b%26c%3Dd");
+    }
+}
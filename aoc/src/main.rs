@@ -0,0 +1,496 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc_core::bench::BenchOptions;
+use aoc_core::input::InputSource;
+use aoc_core::runner::{self, Part, Report, format_duration};
+use aoc_core::{Answer, Result};
+use chrono::Utc;
+use clap::{Args, Parser, Subcommand};
+
+mod answers;
+mod bench;
+mod client;
+mod fetch;
+mod http;
+mod registry;
+mod scaffold;
+mod submit;
+#[cfg(test)]
+mod test_server;
+
+use answers::{AnswerBook, Recorded};
+use registry::Entry;
+use scaffold::Template;
+use submit::{Guess, GuessLog, Verdict};
+
+/// Advent of Code runner for every solved day in this workspace.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Threads for days that split their work (default: one per core).
+    #[arg(long, global = true, value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: Option<u16>,
+    /// Override a puzzle parameter, e.g. `--param threshold=32` for 2018 day 6.
+    #[arg(long = "param", global = true, value_name = "NAME=VALUE", value_parser = aoc_core::params::parse_assignment)]
+    params: Vec<(String, i64)>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day, or every day of a year, and print the answers with timings.
+    Run(RunArgs),
+    /// Create the crate for a new day and register it with the workspace and runner.
+    New(NewArgs),
+    /// Download a day's puzzle input, using the on-disk cache when possible.
+    Fetch(FetchArgs),
+    /// Solve one part and submit the answer, unless the guess log rules it out.
+    Submit(SubmitArgs),
+    /// Re-run solved days and check them against the recorded answers.
+    Verify(VerifyArgs),
+    /// Time parsing and each part separately and append the results to the history file.
+    Bench(BenchArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[arg(long)]
+    year: u16,
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+    /// Only solve this part.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Solve every registered day of the year and print a summary table.
+    #[arg(long)]
+    all: bool,
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    #[arg(long)]
+    year: u16,
+    #[arg(long)]
+    day: u8,
+    #[arg(long, value_enum, default_value_t = Template::Basic)]
+    template: Template,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    #[arg(long)]
+    year: u16,
+    #[arg(long)]
+    day: u8,
+    /// Download again even if the input is already cached.
+    #[arg(long)]
+    force: bool,
+    /// Where to write the input; defaults to the day's `input/input.txt`.
+    #[arg(long, value_name = "PATH")]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    #[arg(long)]
+    year: u16,
+    #[arg(long)]
+    day: u8,
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify this year; defaults to every year with registered days.
+    #[arg(long)]
+    year: Option<u16>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[arg(long)]
+    year: u16,
+    /// Only benchmark this day; defaults to every registered day of the year.
+    #[arg(long)]
+    day: Option<u8>,
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Untimed runs of each phase before measuring.
+    #[arg(long, default_value_t = BenchOptions::default().warmup)]
+    warmup: usize,
+    /// Timed runs of each phase.
+    #[arg(long, default_value_t = BenchOptions::default().runs)]
+    runs: usize,
+    /// Do not append the results to the history file.
+    #[arg(long)]
+    no_save: bool,
+}
+
+/// Where to read the puzzle input from; defaults to the day's `input/input.txt`.
+#[derive(Args)]
+#[group(multiple = false)]
+struct InputArgs {
+    /// Read the input from this file.
+    #[arg(long, value_name = "PATH", conflicts_with = "all")]
+    input: Option<PathBuf>,
+    /// Read the input from standard input.
+    #[arg(long, conflicts_with = "all")]
+    stdin: bool,
+    /// Use the day's `input/example<N>.txt` instead of the real input.
+    #[arg(long, value_name = "N")]
+    example: Option<u32>,
+}
+
+impl InputArgs {
+    fn source(&self) -> InputSource {
+        if let Some(path) = &self.input {
+            InputSource::File(path.clone())
+        } else if self.stdin {
+            InputSource::Stdin
+        } else if let Some(n) = self.example {
+            InputSource::Example(n)
+        } else {
+            InputSource::Puzzle
+        }
+    }
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the aoc crate lives inside the workspace")
+        .to_path_buf()
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Some(jobs) = cli.jobs {
+        aoc_core::parallel::set_jobs(jobs.into());
+    }
+    for (name, value) in &cli.params {
+        aoc_core::params::set(name, *value);
+    }
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::New(args) => new_day(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let parts = match args.part {
+        Some(n) => vec![Part::try_from(n)?],
+        None => Part::BOTH.to_vec(),
+    };
+    let source = args.input.source();
+    if args.all {
+        return run_year(args.year, &parts, &source);
+    }
+    let day = args.day.expect("clap requires --day without --all");
+    let entry = registry::find(args.year, day)
+        .ok_or_else(|| format!("no solution registered for {} day {day}", args.year))?;
+    let report = solve(entry, &parts, &source)?;
+    println!("{} day {}", entry.year, entry.day);
+    println!("  parse: {}", format_duration(report.parse_time));
+    for part in report.parts {
+        let answer = part.answer?;
+        let elapsed = format_duration(part.elapsed);
+        match answer {
+            Answer::Grid(rows) => {
+                println!("  {}: ({elapsed})", part.part);
+                for row in rows {
+                    println!("    {row}");
+                }
+            }
+            answer => println!("  {}: {answer} ({elapsed})", part.part),
+        }
+    }
+    Ok(())
+}
+
+fn new_day(args: NewArgs) -> Result<()> {
+    let dir = scaffold::new_day(&workspace_root(), args.year, args.day, args.template)?;
+    println!("created {}", dir.display());
+    println!(
+        "run `aoc fetch --year {} --day {}` for the input and put the worked example in input/example1.txt",
+        args.year, args.day
+    );
+    Ok(())
+}
+
+fn fetch(args: FetchArgs) -> Result<()> {
+    let client = client::AocClient::from_env()?;
+    let cache = fetch::InputCache::from_env()?;
+    let input = fetch::fetch_input(&client, &cache, args.year, args.day, args.force)?;
+    let output = args.output.unwrap_or_else(|| {
+        aoc_core::input::input_path(
+            &workspace_root().join(format!("{}/aoc{}", args.year, args.day)),
+        )
+    });
+    fetch::write_input(&output, &input)?;
+    println!(
+        "wrote {} lines to {}",
+        input.lines().count(),
+        output.display()
+    );
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> Result<()> {
+    let part = Part::try_from(args.part)?;
+    let entry = registry::find(args.year, args.day)
+        .ok_or_else(|| format!("no solution registered for {} day {}", args.year, args.day))?;
+    let report = solve(entry, &[part], &InputSource::Puzzle)?;
+    let solved = report
+        .parts
+        .into_iter()
+        .next()
+        .expect("one part was solved")
+        .answer?;
+    let answer = match &solved {
+        Answer::Int(n) => n.to_string(),
+        Answer::Text(s) => s.clone(),
+        Answer::Grid(_) => return Err("grid answers have to be read and submitted by hand".into()),
+        Answer::Unsolved => return Err(format!("{part} is not solved yet").into()),
+    };
+
+    let mut log = GuessLog::load(GuessLog::path(&fetch::cache_dir()?, args.year, args.day))?;
+    let now = Utc::now();
+    if let Some(reason) = log.refusal(part, &answer, now) {
+        return Err(format!("not submitting {answer}: {reason}").into());
+    }
+    let client = client::AocClient::from_env()?;
+    let reply = client.submit_answer(args.year, args.day, part, &answer)?;
+    log.record(Guess {
+        part: args.part,
+        answer: answer.clone(),
+        verdict: reply.verdict,
+        at: now,
+        retry_at: reply.wait.map(|wait| now + wait),
+    })?;
+    println!("submitted {answer} for {part}: {}", reply.message);
+    if reply.verdict == Verdict::Correct
+        && let Some(recorded) = Recorded::from_answer(&solved)
+    {
+        let path = AnswerBook::path(&workspace_root(), args.year);
+        AnswerBook::load(path.clone())?.record(args.day, part, recorded)?;
+        println!("recorded the answer in {}", path.display());
+    }
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> Result<()> {
+    let mut years: BTreeSet<u16> = registry::SOLUTIONS.iter().map(|e| e.year).collect();
+    if let Some(year) = args.year {
+        years.retain(|y| *y == year);
+        if years.is_empty() {
+            return Err(format!("no days are registered for {year}").into());
+        }
+    }
+    let mut rows = vec![];
+    let mut failures = 0;
+    for year in years {
+        let book = AnswerBook::load(AnswerBook::path(&workspace_root(), year))?;
+        for entry in registry::year(year) {
+            let Some(expected) = book.day(entry.day) else {
+                continue;
+            };
+            let parts: Vec<Part> = Part::BOTH
+                .into_iter()
+                .filter(|p| expected.get(*p).is_some())
+                .collect();
+            let row = |part: Part, got: String, ok: bool| {
+                let expected = expected
+                    .get(part)
+                    .map(ToString::to_string)
+                    .unwrap_or_default();
+                let status = if ok { "ok" } else { "MISMATCH" };
+                vec![
+                    year.to_string(),
+                    entry.day.to_string(),
+                    part.to_string(),
+                    expected,
+                    got,
+                    status.to_string(),
+                ]
+            };
+            match solve(entry, &parts, &InputSource::Puzzle) {
+                Ok(report) => {
+                    for p in report.parts {
+                        let recorded = expected.get(p.part).expect("only recorded parts are run");
+                        let ok = p.answer.as_ref().is_ok_and(|a| recorded.matches(a));
+                        if !ok {
+                            failures += 1;
+                        }
+                        rows.push(row(p.part, summarize(&p.answer), ok));
+                    }
+                }
+                Err(e) => {
+                    for part in parts {
+                        failures += 1;
+                        rows.push(row(part, format!("error: {e}"), false));
+                    }
+                }
+            }
+        }
+    }
+    if rows.is_empty() {
+        return Err("no recorded answers to verify".into());
+    }
+    print_table(&["year", "day", "part", "expected", "got", "status"], &rows);
+    if failures > 0 {
+        return Err(format!("{failures} recorded answers no longer match").into());
+    }
+    Ok(())
+}
+
+fn bench(args: BenchArgs) -> Result<()> {
+    let parts = match args.part {
+        Some(n) => vec![Part::try_from(n)?],
+        None => Part::BOTH.to_vec(),
+    };
+    let entries: Vec<&Entry> = registry::year(args.year)
+        .filter(|e| args.day.is_none_or(|day| e.day == day))
+        .collect();
+    if entries.is_empty() {
+        return Err(format!("nothing registered to benchmark for {}", args.year).into());
+    }
+    let options = BenchOptions {
+        warmup: args.warmup,
+        runs: args.runs,
+    };
+    let root = workspace_root();
+    let commit = bench::current_commit(&root);
+    let now = Utc::now();
+    let mut history = bench::History::load(bench::History::path(&root))?;
+    let mut records = vec![];
+    let mut rows = vec![];
+    for entry in entries {
+        let input = InputSource::Puzzle.read(&root.join(entry.dir))?;
+        let report = aoc_core::bench::bench(entry.solution, &input, &parts, options)?;
+        let phases = std::iter::once(("parse".to_string(), report.parse)).chain(
+            report
+                .parts
+                .into_iter()
+                .map(|(part, stats)| (part.to_string(), stats)),
+        );
+        for (phase, stats) in phases {
+            let record = bench::Record::new(&commit, now, entry.year, entry.day, &phase, stats);
+            let change = match history.previous(&record) {
+                Some(previous) => format!(
+                    "{} vs {}",
+                    bench::change(previous, &record),
+                    previous.commit
+                ),
+                None => String::new(),
+            };
+            rows.push(vec![
+                entry.day.to_string(),
+                phase,
+                format_duration(stats.median),
+                format!("±{}", format_duration(stats.mad)),
+                format_duration(stats.min),
+                format_duration(stats.max),
+                change,
+            ]);
+            records.push(record);
+        }
+    }
+    print_table(
+        &["day", "phase", "median", "mad", "min", "max", "change"],
+        &rows,
+    );
+    if !args.no_save {
+        history.append(records)?;
+    }
+    Ok(())
+}
+
+fn solve(entry: &Entry, parts: &[Part], source: &InputSource) -> Result<Report> {
+    let input = source.read(&workspace_root().join(entry.dir))?;
+    runner::run(entry.solution_for(source), &input, parts)
+}
+
+fn run_year(year: u16, parts: &[Part], source: &InputSource) -> Result<()> {
+    let entries: Vec<&Entry> = registry::year(year).collect();
+    if entries.is_empty() {
+        return Err(format!("no solutions registered for {year}").into());
+    }
+    let mut rows = vec![];
+    for entry in entries {
+        let mut row = vec![entry.day.to_string()];
+        match solve(entry, parts, source) {
+            Ok(report) => {
+                for part in Part::BOTH {
+                    let cell = match report.parts.iter().find(|p| p.part == part) {
+                        Some(p) => summarize(&p.answer),
+                        None => "-".to_string(),
+                    };
+                    row.push(cell);
+                }
+                row.push(format_duration(report.total_time()));
+            }
+            Err(e) => {
+                row.push(format!("error: {e}"));
+                row.push(String::new());
+                row.push(String::new());
+            }
+        }
+        rows.push(row);
+    }
+    print_table(&["day", "part1", "part2", "time"], &rows);
+    Ok(())
+}
+
+fn summarize(answer: &Result<Answer>) -> String {
+    match answer {
+        Ok(Answer::Grid(rows)) => format!("<grid, {} rows>", rows.len()),
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("error: {e}"),
+    }
+}
+
+fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.chars().count());
+        }
+    }
+    let line = |cells: Vec<&str>| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, w)| format!("{cell:<w$}"))
+            .collect();
+        println!("{}", padded.join(" | ").trim_end());
+    };
+    line(header.to_vec());
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|w| "-".repeat(*w))
+            .collect::<Vec<_>>()
+            .join("-+-")
+    );
+    for row in rows {
+        line(row.iter().map(String::as_str).collect());
+    }
+}
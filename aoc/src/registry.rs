@@ -0,0 +1,96 @@
+use aoc_core::input::InputSource;
+use aoc_core::runner::DynSolution;
+
+/// A solved day and where its crate lives relative to the workspace root.
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub dir: &'static str,
+    pub solution: &'static dyn DynSolution,
+    /// Used instead of `solution` on the worked examples, for days whose
+    /// puzzle parameters are smaller there.
+    pub example: Option<&'static dyn DynSolution>,
+}
+
+impl Entry {
+    /// The solution to run on input from `source`.
+    pub fn solution_for(&self, source: &InputSource) -> &'static dyn DynSolution {
+        match (source, self.example) {
+            (InputSource::Example(_), Some(example)) => example,
+            _ => self.solution,
+        }
+    }
+}
+
+macro_rules! registry {
+    ($($year:literal, $day:literal => $solution:path $(; example = $example:path)?,)*) => {
+        pub static SOLUTIONS: &[Entry] = &[
+            $(Entry {
+                year: $year,
+                day: $day,
+                dir: concat!(stringify!($year), "/aoc", stringify!($day)),
+                solution: &$solution,
+                example: registry!(@example $($example)?),
+            },)*
+        ];
+    };
+    (@example $example:path) => { Some(&$example) };
+    (@example) => { None };
+}
+
+// One line per day, kept in (year, day) order; `aoc new` inserts new days here.
+// A day whose examples need other parameters names a second solution after
+// `; example =`.
+registry! {
+    2018, 1 => aoc2018_day1::Day1,
+    2018, 2 => aoc2018_day2::Day2,
+    2018, 3 => aoc2018_day3::Day3,
+    2018, 4 => aoc2018_day4::Day4,
+    2018, 5 => aoc2018_day5::Day5,
+    2018, 6 => aoc2018_day6::Day6::PUZZLE; example = aoc2018_day6::Day6::EXAMPLE,
+    2024, 1 => aoc2024_day1::Day1,
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Entry> {
+    SOLUTIONS.iter().find(|e| e.year == year && e.day == day)
+}
+
+pub fn year(year: u16) -> impl Iterator<Item = &'static Entry> {
+    SOLUTIONS.iter().filter(move |e| e.year == year)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn entries_are_unique() {
+        let keys: HashSet<(u16, u8)> = SOLUTIONS.iter().map(|e| (e.year, e.day)).collect();
+        assert_eq!(keys.len(), SOLUTIONS.len());
+    }
+
+    #[test]
+    fn dirs_match_year_and_day() {
+        for e in SOLUTIONS {
+            assert_eq!(e.dir, format!("{}/aoc{}", e.year, e.day));
+        }
+    }
+
+    #[test]
+    fn examples_use_their_own_parameters() -> aoc_core::Result<()> {
+        let entry = find(2018, 6).expect("2018 day 6 is registered");
+        let input = include_str!("../../2018/aoc6/input/example1.txt");
+        let part2 = |source: &InputSource| -> aoc_core::Result<_> {
+            let report = aoc_core::runner::run(
+                entry.solution_for(source),
+                input,
+                &[aoc_core::runner::Part::Two],
+            )?;
+            Ok(report.parts[0].answer.as_ref().ok().cloned())
+        };
+        assert_eq!(part2(&InputSource::Example(1))?, Some(16.into()));
+        assert_ne!(part2(&InputSource::Puzzle)?, Some(16.into()));
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use aoc_core::Result;
+use clap::ValueEnum;
+use regex::Regex;
+
+/// Starting point for a new day's `lib.rs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Template {
+    /// Input kept as a single trimmed string.
+    Basic,
+    /// One `FromStr` record per input line.
+    Lines,
+    /// Rectangular grid of bytes.
+    Grid,
+}
+
+impl Template {
+    fn source(self) -> &'static str {
+        match self {
+            Template::Basic => include_str!("../templates/basic.rs"),
+            Template::Lines => include_str!("../templates/lines.rs"),
+            Template::Grid => include_str!("../templates/grid.rs"),
+        }
+    }
+
+    pub fn render(self, day: u8) -> String {
+        self.source().replace("{{day}}", &day.to_string())
+    }
+}
+
+/// Creates the crate for `year`/`day` under `root` and wires it into the
+/// workspace manifest, the runner's dependencies and the solution registry.
+///
+/// Returns the new crate's directory.
+pub fn new_day(root: &Path, year: u16, day: u8, template: Template) -> Result<PathBuf> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day must be between 1 and 25, got {day}").into());
+    }
+    let rel_dir = format!("{year}/aoc{day}");
+    let dir = root.join(&rel_dir);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()).into());
+    }
+    let package = format!("aoc{year}-day{day}");
+
+    // Update the shared files first so a malformed one leaves no half-built crate behind.
+    let workspace_manifest = root.join("Cargo.toml");
+    let mut manifest = fs::read_to_string(&workspace_manifest)?;
+    manifest = insert_sorted(&manifest, &format!("    \"{rel_dir}\","))?;
+    manifest = insert_sorted(
+        &manifest,
+        &format!("{package} = {{ path = \"{rel_dir}\" }}"),
+    )?;
+    let runner_manifest = root.join("aoc/Cargo.toml");
+    let runner_deps = insert_sorted(
+        &fs::read_to_string(&runner_manifest)?,
+        &format!("{package}.workspace = true"),
+    )?;
+    let registry_path = root.join("aoc/src/registry.rs");
+    let registry = insert_sorted(
+        &fs::read_to_string(&registry_path)?,
+        &format!("    {year}, {day} => aoc{year}_day{day}::Day{day},"),
+    )?;
+
+    fs::create_dir_all(dir.join("src"))?;
+    fs::create_dir_all(dir.join("input"))?;
+    fs::write(
+        dir.join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"{package}\"\nversion.workspace = true\nedition.workspace = true\n\n[dependencies]\naoc-core.workspace = true\n"
+        ),
+    )?;
+    fs::write(dir.join("src/lib.rs"), template.render(day))?;
+    fs::write(aoc_core::input::example_path(&dir, 1), "")?;
+
+    fs::write(&workspace_manifest, manifest)?;
+    fs::write(&runner_manifest, runner_deps)?;
+    fs::write(&registry_path, registry)?;
+    Ok(dir)
+}
+
+static DAY_KEY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?<year>\d{4})(?:/aoc|-day|_day|, )(?<day>\d+)").expect("regex compiles")
+});
+
+fn day_key(line: &str) -> Option<(u16, u8)> {
+    let cap = DAY_KEY.captures(line)?;
+    Some((cap["year"].parse().ok()?, cap["day"].parse().ok()?))
+}
+
+/// Inserts `line` into the block of lines in `text` that look like `line`,
+/// keeping that block in (year, day) order.
+///
+/// A block is a run of consecutive lines sharing the new line's shape: the
+/// same text before the first year/day match.
+fn insert_sorted(text: &str, line: &str) -> Result<String> {
+    let key = day_key(line).ok_or_else(|| format!("no year/day in {line:?}"))?;
+    let prefix = shape(line);
+    let mut lines: Vec<&str> = text.lines().collect();
+    let block: Vec<usize> = (0..lines.len())
+        .filter(|&i| day_key(lines[i]).is_some() && shape(lines[i]) == prefix)
+        .collect();
+    let (&first, &last) = block
+        .first()
+        .zip(block.last())
+        .ok_or_else(|| format!("nowhere to insert {line:?}"))?;
+    if last - first + 1 != block.len() {
+        return Err(format!("entries like {line:?} are not in one block").into());
+    }
+    if block.iter().any(|&i| day_key(lines[i]) == Some(key)) {
+        return Err(format!("{} day {} is already registered", key.0, key.1).into());
+    }
+    let at = block
+        .iter()
+        .copied()
+        .find(|&i| day_key(lines[i]) > Some(key))
+        .unwrap_or(last + 1);
+    lines.insert(at, line);
+    let mut out = lines.join("\n");
+    if text.ends_with('\n') {
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+fn shape(line: &str) -> &str {
+    let start = DAY_KEY.find(line).map_or(line.len(), |m| m.start());
+    &line[..start]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn insert_keeps_numeric_order() -> Result<()> {
+        let text = "members = [\n    \"aoc\",\n    \"2018/aoc2\",\n    \"2018/aoc10\",\n]\n";
+        let out = insert_sorted(text, "    \"2018/aoc3\",")?;
+        assert_eq!(
+            out,
+            "members = [\n    \"aoc\",\n    \"2018/aoc2\",\n    \"2018/aoc3\",\n    \"2018/aoc10\",\n]\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn insert_appends_after_last_entry() -> Result<()> {
+        let text = "[dependencies]\naoc-core.workspace = true\naoc2018-day1.workspace = true\nclap.workspace = true";
+        let out = insert_sorted(text, "aoc2024-day1.workspace = true")?;
+        assert_eq!(
+            out,
+            "[dependencies]\naoc-core.workspace = true\naoc2018-day1.workspace = true\naoc2024-day1.workspace = true\nclap.workspace = true"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn insert_only_touches_matching_block() -> Result<()> {
+        let text = "    \"2018/aoc1\",\n\naoc2018-day1 = { path = \"2018/aoc1\" }\n";
+        let out = insert_sorted(text, "aoc2018-day2 = { path = \"2018/aoc2\" }")?;
+        assert_eq!(
+            out,
+            "    \"2018/aoc1\",\n\naoc2018-day1 = { path = \"2018/aoc1\" }\naoc2018-day2 = { path = \"2018/aoc2\" }\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn insert_rejects_duplicates() {
+        let text = "    2018, 1 => aoc2018_day1::Day1,\n";
+        assert!(insert_sorted(text, "    2018, 1 => aoc2018_day1::Day1,").is_err());
+    }
+
+    #[test]
+    fn templates_render_day() {
+        for template in [Template::Basic, Template::Lines, Template::Grid] {
+            let source = template.render(7);
+            assert!(source.contains("pub struct Day7;"));
+            assert!(!source.contains("{{"));
+        }
+    }
+
+    #[test]
+    fn new_day_registers_everywhere() -> Result<()> {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc/src"))?;
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2018/aoc1\",\n]\n\n[workspace.dependencies]\naoc2018-day1 = { path = \"2018/aoc1\" }\n",
+        )?;
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]\naoc2018-day1.workspace = true\n",
+        )?;
+        fs::write(
+            root.join("aoc/src/registry.rs"),
+            "registry! {\n    2018, 1 => aoc2018_day1::Day1,\n}\n",
+        )?;
+
+        let dir = new_day(&root, 2018, 2, Template::Lines)?;
+        assert!(dir.join("src/lib.rs").exists());
+        assert!(dir.join("input/example1.txt").exists());
+        let manifest = fs::read_to_string(root.join("Cargo.toml"))?;
+        assert!(manifest.contains("    \"2018/aoc2\",\n]"));
+        assert!(manifest.contains("aoc2018-day2 = { path = \"2018/aoc2\" }"));
+        let registry = fs::read_to_string(root.join("aoc/src/registry.rs"))?;
+        assert!(registry.contains("    2018, 2 => aoc2018_day2::Day2,\n}"));
+        assert!(new_day(&root, 2018, 2, Template::Basic).is_err());
+
+        fs::remove_dir_all(&root)?;
+        Ok(())
+    }
+}
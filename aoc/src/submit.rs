@@ -0,0 +1,326 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use aoc_core::runner::Part;
+use aoc_core::{Error, Result};
+use chrono::{DateTime, Duration, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// How the site judged a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about the direction.
+    Wrong,
+    /// The answer was not checked because we submitted too soon.
+    RateLimited,
+    /// The part was already solved, so the answer was not checked.
+    AlreadySolved,
+    Unknown,
+}
+
+/// The site's reply to a submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reply {
+    pub verdict: Verdict,
+    /// How long the site asks us to wait before the next submission.
+    pub wait: Option<Duration>,
+    /// Text of the reply's `<article>`, with markup removed.
+    pub message: String,
+}
+
+static ARTICLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<article>(.*?)</article>").expect("regex compiles"));
+static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]*>").expect("regex compiles"));
+static LEFT_TO_WAIT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)you have (?:(?<m>\d+)m )?(?<s>\d+)s left to wait").expect("regex compiles")
+});
+static WAIT_MINUTES: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"wait (?<n>one|\d+) minutes? before trying again").expect("regex compiles")
+});
+
+impl Reply {
+    pub fn parse(html: &str) -> Self {
+        let article = ARTICLE
+            .captures(html)
+            .map_or(html, |c| c.get(1).unwrap().as_str());
+        let message = TAG.replace_all(article, "");
+        let message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+        let verdict = if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::RateLimited
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else if message.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if message.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if message.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else {
+            Verdict::Unknown
+        };
+        Reply {
+            verdict,
+            wait: parse_wait(&message),
+            message,
+        }
+    }
+}
+
+fn parse_wait(message: &str) -> Option<Duration> {
+    if let Some(cap) = LEFT_TO_WAIT.captures(message) {
+        let minutes: i64 = cap.name("m").map_or(Ok(0), |m| m.as_str().parse()).ok()?;
+        let seconds: i64 = cap["s"].parse().ok()?;
+        return Some(Duration::seconds(minutes * 60 + seconds));
+    }
+    let cap = WAIT_MINUTES.captures(message)?;
+    let minutes = match &cap["n"] {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+    Some(Duration::minutes(minutes))
+}
+
+/// One submission and how it went.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    pub at: DateTime<Utc>,
+    /// When the site will accept the next submission, if it asked us to wait.
+    pub retry_at: Option<DateTime<Utc>>,
+}
+
+/// Every guess made for one day, stored as one JSON object per line.
+pub struct GuessLog {
+    path: PathBuf,
+    guesses: Vec<Guess>,
+}
+
+impl GuessLog {
+    pub fn path(dir: &Path, year: u16, day: u8) -> PathBuf {
+        dir.join(year.to_string())
+            .join(format!("day{day}.guesses.jsonl"))
+    }
+
+    pub fn load(path: PathBuf) -> Result<Self> {
+        let guesses = match fs::read_to_string(&path) {
+            Ok(text) => text
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(serde_json::from_str)
+                .collect::<std::result::Result<_, _>>()
+                .map_err(|e| format!("corrupt guess log {}: {e}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => vec![],
+            Err(e) => return Err(e.into()),
+        };
+        Ok(GuessLog { path, guesses })
+    }
+
+    /// Explains why `answer` should not be submitted for `part`, if there is a reason.
+    pub fn refusal(&self, part: Part, answer: &str, now: DateTime<Utc>) -> Option<String> {
+        if let Some(retry_at) = self.guesses.iter().filter_map(|g| g.retry_at).max()
+            && retry_at > now
+        {
+            let wait = (retry_at - now).num_seconds();
+            return Some(format!("the site asked us to wait; try again in {wait}s"));
+        }
+        let level = part_number(part);
+        let guesses = || self.guesses.iter().filter(move |g| g.part == level);
+        if let Some(correct) = guesses().find(|g| g.verdict == Verdict::Correct) {
+            return Some(format!("{part} is already solved with {}", correct.answer));
+        }
+        if let Some(guess) = guesses().find(|g| g.answer == answer && is_wrong(g.verdict)) {
+            return Some(format!(
+                "{answer} was already rejected on {}",
+                guess.at.format("%Y-%m-%d %H:%M")
+            ));
+        }
+        let value: i64 = answer.parse().ok()?;
+        let numeric = |verdict| {
+            guesses()
+                .filter(move |g| g.verdict == verdict)
+                .filter_map(|g| g.answer.parse::<i64>().ok())
+        };
+        if let Some(high) = numeric(Verdict::TooHigh).min()
+            && value >= high
+        {
+            return Some(format!("{answer} is not below {high}, which was too high"));
+        }
+        if let Some(low) = numeric(Verdict::TooLow).max()
+            && value <= low
+        {
+            return Some(format!("{answer} is not above {low}, which was too low"));
+        }
+        None
+    }
+
+    pub fn record(&mut self, guess: Guess) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(
+            file,
+            "{}",
+            serde_json::to_string(&guess).map_err(Error::other)?
+        )?;
+        self.guesses.push(guess);
+        Ok(())
+    }
+}
+
+fn is_wrong(verdict: Verdict) -> bool {
+    matches!(verdict, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+}
+
+pub fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::AocClient;
+    use crate::http::UreqBackend;
+    use crate::test_server::TestServer;
+
+    const CORRECT: &str = "<main>\n<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to saving Christmas. <a href=\"/2018/day/4#part2\">[Continue to Part Two]</a></p></article>\n</main>";
+    const TOO_HIGH: &str = "<main>\n<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href=\"/2018/day/4\">[Return to Day 4]</a></p></article>\n</main>";
+    const TOO_RECENT: &str = "<main>\n<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 12s left to wait. <a href=\"/2018/day/4\">[Return to Day 4]</a></p></article>\n</main>";
+
+    fn guess(part: u8, answer: &str, verdict: Verdict) -> Guess {
+        Guess {
+            part,
+            answer: answer.to_string(),
+            verdict,
+            at: DateTime::UNIX_EPOCH,
+            retry_at: None,
+        }
+    }
+
+    fn log(guesses: Vec<Guess>) -> GuessLog {
+        GuessLog {
+            path: PathBuf::new(),
+            guesses,
+        }
+    }
+
+    #[test]
+    fn parse_replies() {
+        let reply = Reply::parse(CORRECT);
+        assert_eq!(reply.verdict, Verdict::Correct);
+        assert!(reply.message.starts_with("That's the right answer!"));
+        let reply = Reply::parse(TOO_HIGH);
+        assert_eq!(reply.verdict, Verdict::TooHigh);
+        assert_eq!(reply.wait, Some(Duration::minutes(1)));
+        let reply = Reply::parse(TOO_RECENT);
+        assert_eq!(reply.verdict, Verdict::RateLimited);
+        assert_eq!(reply.wait, Some(Duration::seconds(252)));
+        let reply = Reply::parse(
+            "<article><p>That's not the right answer; your answer is too low. Please wait 5 minutes before trying again.</p></article>",
+        );
+        assert_eq!(reply.verdict, Verdict::TooLow);
+        assert_eq!(reply.wait, Some(Duration::minutes(5)));
+    }
+
+    #[test]
+    fn refuses_known_wrong_answer() {
+        let log = log(vec![guess(1, "abc", Verdict::Wrong)]);
+        assert!(log.refusal(Part::One, "abc", Utc::now()).is_some());
+        assert!(log.refusal(Part::One, "abd", Utc::now()).is_none());
+        assert!(log.refusal(Part::Two, "abc", Utc::now()).is_none());
+    }
+
+    #[test]
+    fn refuses_answers_outside_bounds() {
+        let log = log(vec![
+            guess(1, "500", Verdict::TooHigh),
+            guess(1, "100", Verdict::TooLow),
+            guess(1, "400", Verdict::TooHigh),
+        ]);
+        let now = Utc::now();
+        assert!(log.refusal(Part::One, "450", now).is_some());
+        assert!(log.refusal(Part::One, "400", now).is_some());
+        assert!(log.refusal(Part::One, "100", now).is_some());
+        assert!(log.refusal(Part::One, "99", now).is_some());
+        assert!(log.refusal(Part::One, "399", now).is_none());
+    }
+
+    #[test]
+    fn refuses_while_rate_limited() {
+        let now = Utc::now();
+        let mut limited = guess(1, "5", Verdict::RateLimited);
+        limited.retry_at = Some(now + Duration::seconds(30));
+        let log = log(vec![limited]);
+        assert!(log.refusal(Part::One, "7", now).is_some());
+        assert!(
+            log.refusal(Part::One, "7", now + Duration::seconds(31))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn refuses_solved_part() {
+        let log = log(vec![guess(2, "42", Verdict::Correct)]);
+        assert!(log.refusal(Part::Two, "43", Utc::now()).is_some());
+    }
+
+    #[test]
+    fn log_round_trips() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-guesses-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let path = GuessLog::path(&dir, 2018, 4);
+        let mut log = GuessLog::load(path.clone())?;
+        log.record(guess(1, "10", Verdict::TooLow))?;
+        log.record(guess(1, "20", Verdict::Correct))?;
+        let reloaded = GuessLog::load(path)?;
+        assert_eq!(reloaded.guesses, log.guesses);
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn submit_posts_level_and_answer() -> Result<()> {
+        let server = TestServer::start(|request| {
+            assert_eq!(request.method, "POST");
+            assert_eq!(request.path, "/2018/day/4/answer");
+            let reply = if request.body == "level=2&answer=71976" {
+                CORRECT
+            } else {
+                TOO_HIGH
+            };
+            (200, reply.to_string())
+        });
+        let client = AocClient::new(UreqBackend::new(), server.url(), "secret");
+        assert_eq!(
+            client.submit_answer(2018, 4, Part::Two, "71976")?.verdict,
+            Verdict::Correct
+        );
+        assert_eq!(
+            client.submit_answer(2018, 4, Part::Two, "99999")?.verdict,
+            Verdict::TooHigh
+        );
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].header("content-type"),
+            Some("application/x-www-form-urlencoded")
+        );
+        Ok(())
+    }
+}
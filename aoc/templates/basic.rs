@@ -0,0 +1,40 @@
+use aoc_core::{Answer, Result, Solution};
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    type Parsed = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(&self, _input: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn part2(&self, _input: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/example1.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let input = Day{{day}}.parse(EXAMPLE)?;
+        assert_eq!(Day{{day}}.part1(&input)?, Answer::Unsolved);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let input = Day{{day}}.parse(EXAMPLE)?;
+        assert_eq!(Day{{day}}.part2(&input)?, Answer::Unsolved);
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use aoc_core::grid::Grid;
+use aoc_core::{Answer, Result, Solution};
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    /// The input bytes with `(0, 0)` at the top left.
+    type Parsed = Grid<u8>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Grid::parse(input)
+    }
+
+    fn part1(&self, _grid: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn part2(&self, _grid: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/example1.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let grid = Day{{day}}.parse(EXAMPLE)?;
+        assert_eq!(Day{{day}}.part1(&grid)?, Answer::Unsolved);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let grid = Day{{day}}.parse(EXAMPLE)?;
+        assert_eq!(Day{{day}}.part2(&grid)?, Answer::Unsolved);
+        Ok(())
+    }
+}
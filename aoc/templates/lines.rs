@@ -0,0 +1,64 @@
+use aoc_core::{Answer, Result, Solution, parse};
+use std::str::FromStr;
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    type Parsed = Vec<Record>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse::lines(input)
+    }
+
+    fn part1(&self, _records: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn part2(&self, _records: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+/// One line of the puzzle input.
+#[derive(Debug)]
+pub struct Record {
+    pub text: String,
+}
+
+impl FromStr for Record {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Record {
+            text: s.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/example1.txt");
+
+    #[test]
+    fn parse_example() -> Result<()> {
+        let records = Day{{day}}.parse(EXAMPLE)?;
+        assert!(records.iter().all(|r| !r.text.is_empty()));
+        Ok(())
+    }
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let records = Day{{day}}.parse(EXAMPLE)?;
+        assert_eq!(Day{{day}}.part1(&records)?, Answer::Unsolved);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let records = Day{{day}}.parse(EXAMPLE)?;
+        assert_eq!(Day{{day}}.part2(&records)?, Answer::Unsolved);
+        Ok(())
+    }
+}